@@ -0,0 +1,115 @@
+//! Compile the versification of `src/bible.json` and the text of
+//! `src/bible_text.json` into static tables, so that the embedded Bible needs
+//! no JSON parsing at run time.
+//!
+//! Both files are in the format of a data pack, their books being keyed by the
+//! serde names of `BibleEnum`, whose variants give the order of the tables. The
+//! counts and the text are checked by `DataPack::validate`, in the tests of
+//! `init_bible`.
+//!
+//! `src/bible_text.json` is the `text.json` of the data pack made by
+//! `read-bible import <KJV OSIS file> -o <directory>`, so it can be regenerated
+//! from any edition of the King James Version.
+
+use std::{collections::BTreeMap, fmt::Write, path::Path};
+
+fn main() {
+    println!("cargo:rerun-if-changed=src/bible.json");
+    println!("cargo:rerun-if-changed=src/bible_text.json");
+    println!("cargo:rerun-if-changed=src/bible_enum.rs");
+    println!("cargo:rerun-if-changed=build.rs");
+
+    let json = std::fs::read_to_string("src/bible.json").expect("src/bible.json is readable");
+    let mut books: BTreeMap<String, Vec<u8>> =
+        serde_json::from_str(&json).expect("src/bible.json is valid");
+    let text_json =
+        std::fs::read_to_string("src/bible_text.json").expect("src/bible_text.json is readable");
+    let mut texts: BTreeMap<String, Vec<Vec<String>>> =
+        serde_json::from_str(&text_json).expect("src/bible_text.json is valid");
+
+    let keys: Vec<String> = book_keys();
+
+    let mut verse_counts = String::new();
+    let mut chapter_offsets = String::new();
+    let mut verse_text = String::new();
+    let mut total: u32 = 0;
+
+    for key in &keys {
+        let counts: Vec<u8> = books
+            .remove(key)
+            .unwrap_or_else(|| panic!("src/bible.json has no book {key}"));
+
+        let mut offsets: Vec<u32> = Vec::with_capacity(counts.len());
+        for count in &counts {
+            offsets.push(total);
+            total += *count as u32;
+        }
+
+        verse_text.push_str("    &[");
+        for chapter_text in texts.remove(key).unwrap_or_default() {
+            write!(verse_text, "&{chapter_text:?},").unwrap();
+        }
+        verse_text.push_str("],\n");
+
+        writeln!(verse_counts, "    &{counts:?},").unwrap();
+        writeln!(chapter_offsets, "    &{offsets:?},").unwrap();
+    }
+
+    assert!(
+        books.is_empty() && texts.is_empty(),
+        "src/bible.json or src/bible_text.json has unknown books: {:?}",
+        books.keys().chain(texts.keys()).collect::<Vec<_>>()
+    );
+
+    let tables = format!(
+        "/// The number of verses of every chapter of every book, in the order of\n\
+         /// `BibleEnum`, a count of zero marking a missing chapter\n\
+         pub(crate) static VERSE_COUNTS: [&[u8]; {book_count}] = [\n{verse_counts}];\n\n\
+         /// The number of verses of the Bible before every chapter of every book\n\
+         pub(crate) static CHAPTER_OFFSETS: [&[u32]; {book_count}] = [\n{chapter_offsets}];\n\n\
+         /// The number of verses of the Bible\n\
+         pub(crate) const VERSE_TOTAL: u32 = {total};\n\n\
+         /// The text of the verses of every chapter of every book, a chapter\n\
+         /// without text being empty\n\
+         pub(crate) static VERSE_TEXT: [&[&[&str]]; {book_count}] = [\n{verse_text}];\n",
+        book_count = keys.len(),
+    );
+
+    let out_dir = std::env::var_os("OUT_DIR").expect("cargo sets OUT_DIR");
+    std::fs::write(Path::new(&out_dir).join("tables.rs"), tables).expect("OUT_DIR is writable");
+}
+
+/// The serde names of the variants of `BibleEnum`, in their order, read from
+/// the invocation of `make_bible_enum!`
+fn book_keys() -> Vec<String> {
+    let source =
+        std::fs::read_to_string("src/bible_enum.rs").expect("src/bible_enum.rs is readable");
+    let variants: &str = source
+        .split_once("BibleEnum {")
+        .and_then(|(_, rest)| rest.split_once('}'))
+        .map(|(variants, _)| variants)
+        .expect("src/bible_enum.rs lists the variants of BibleEnum");
+
+    variants
+        .lines()
+        .map(|line| line.split("//").next().unwrap_or_default())
+        .flat_map(|line| line.split(','))
+        .map(str::trim)
+        .filter(|variant| !variant.is_empty())
+        .map(kebab_case)
+        .collect()
+}
+
+/// A variant name as renamed by `#[serde(rename_all = "kebab-case")]`
+fn kebab_case(variant: &str) -> String {
+    let mut key = String::with_capacity(variant.len() + 4);
+
+    for (index, character) in variant.char_indices() {
+        if index > 0 && character.is_uppercase() {
+            key.push('-');
+        }
+        key.push(character.to_ascii_lowercase());
+    }
+
+    key
+}
@@ -0,0 +1,219 @@
+use std::collections::HashMap;
+
+use lazy_static::lazy_static;
+use strum::IntoEnumIterator;
+
+use crate::{
+    bible_enum::{BibleEnum, BibleEnumError},
+    constants::{
+        ALTERNATIVE_NAME_LIST, BOOK_ALIAS_LIST, ENGLISH_BOOK_NAME_LIST, FRENCH_BOOK_NAME_LIST,
+        OSIS_ID_LIST, SBL_ABBREVIATION_LIST, USFM_ID_LIST,
+    },
+};
+
+/// The shortest input completed to a full book name, like "Gene" for Genesis
+const MIN_PREFIX_LENGTH: usize = 3;
+
+lazy_static! {
+    static ref ALIASES: HashMap<String, BibleEnum> = book_aliases()
+        .map(|(book, alias)| (normalize(&alias), book))
+        .collect();
+}
+
+impl std::str::FromStr for BibleEnum {
+    type Err = BibleEnumError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_book(s)
+    }
+}
+
+/// Find a book from its name, abbreviation or OSIS, USFM or Paratext code.
+///
+/// The case, the accents, the spaces and the periods are ignored, and the
+/// ordinals can be written "1", "1st", "I" or "First". An unambiguous start of
+/// a full name is accepted too.
+pub fn parse_book(input: &str) -> Result<BibleEnum, BibleEnumError> {
+    if let Some(book) = find_alias(input) {
+        return Ok(book);
+    }
+
+    let key = normalize(input);
+
+    if key.chars().count() < MIN_PREFIX_LENGTH {
+        return Err(BibleEnumError);
+    }
+
+    let mut candidates = BibleEnum::iter().filter(|book| {
+        [ENGLISH_BOOK_NAME_LIST, FRENCH_BOOK_NAME_LIST]
+            .iter()
+            .any(|names| normalize(names[*book as usize]).starts_with(&key))
+    });
+
+    match (candidates.next(), candidates.next()) {
+        (Some(book), None) => Ok(book),
+        _ => Err(BibleEnumError),
+    }
+}
+
+/// Find a book from one of its names, abbreviations or codes, without
+/// completing the start of a name
+pub(crate) fn find_alias(input: &str) -> Option<BibleEnum> {
+    ALIASES.get(&normalize(input)).copied()
+}
+
+/// Every known way to write every book
+fn book_aliases() -> impl Iterator<Item = (BibleEnum, String)> {
+    BibleEnum::iter().flat_map(|book| {
+        let index = book as usize;
+
+        [
+            format!("{book:?}"),
+            ENGLISH_BOOK_NAME_LIST[index].to_string(),
+            FRENCH_BOOK_NAME_LIST[index].to_string(),
+            OSIS_ID_LIST[index].to_string(),
+            SBL_ABBREVIATION_LIST[index].to_string(),
+            USFM_ID_LIST[index].to_string(),
+        ]
+        .into_iter()
+        .chain(
+            BOOK_ALIAS_LIST[index]
+                .iter()
+                .chain(ALTERNATIVE_NAME_LIST[index])
+                .map(|alias| alias.to_string()),
+        )
+        .map(move |alias| (book, alias))
+    })
+}
+
+/// Lowercase the input, remove its accents, punctuation and spaces, and write
+/// its leading ordinal as a digit
+fn normalize(input: &str) -> String {
+    let folded: String = input
+        .chars()
+        .flat_map(char::to_lowercase)
+        .filter(|c| !matches!(c, '\'' | '’'))
+        .map(|c| match c {
+            'à' | 'â' | 'ä' => 'a',
+            'é' | 'è' | 'ê' | 'ë' => 'e',
+            'î' | 'ï' => 'i',
+            'ô' | 'ö' => 'o',
+            'ù' | 'û' | 'ü' => 'u',
+            'ç' => 'c',
+            '.' | '-' | '_' => ' ',
+            c => c,
+        })
+        .collect();
+
+    let mut words: Vec<&str> = folded.split_whitespace().collect();
+
+    if words.len() > 1 {
+        if let Some(digit) = ordinal(words[0]) {
+            words[0] = digit;
+        }
+    }
+
+    let joined = words.concat();
+
+    for (word, digit) in [
+        ("first", "1"),
+        ("second", "2"),
+        ("third", "3"),
+        ("fourth", "4"),
+    ] {
+        if let Some(rest) = joined.strip_prefix(word) {
+            if !rest.is_empty() {
+                return format!("{digit}{rest}");
+            }
+        }
+    }
+
+    joined
+}
+
+fn ordinal(word: &str) -> Option<&'static str> {
+    match word {
+        "1" | "i" | "1st" | "first" | "1er" | "1re" | "premier" | "premiere" => Some("1"),
+        "2" | "ii" | "2nd" | "second" | "2e" | "seconde" | "deuxieme" => Some("2"),
+        "3" | "iii" | "3rd" | "third" | "3e" | "troisieme" => Some("3"),
+        "4" | "iv" | "4th" | "fourth" | "4e" | "quatrieme" => Some("4"),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use super::*;
+
+    #[test]
+    fn test_aliases_are_unambiguous() {
+        let mut seen: HashMap<String, BibleEnum> = HashMap::new();
+
+        for (book, alias) in book_aliases() {
+            let previous = seen.insert(normalize(&alias), book);
+            assert!(
+                previous.is_none() || previous == Some(book),
+                "{alias} names {previous:?} and {book:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_every_book_parses_back() {
+        for book in BibleEnum::iter() {
+            assert_eq!(BibleEnum::from_str(&format!("{book:?}")).unwrap(), book);
+            assert_eq!(
+                BibleEnum::from_str(ENGLISH_BOOK_NAME_LIST[book as usize]).unwrap(),
+                book
+            );
+            assert_eq!(
+                BibleEnum::from_str(OSIS_ID_LIST[book as usize]).unwrap(),
+                book
+            );
+            assert_eq!(
+                BibleEnum::from_str(USFM_ID_LIST[book as usize]).unwrap(),
+                book
+            );
+        }
+    }
+
+    #[test]
+    fn test_abbreviations() {
+        assert_eq!(parse_book("gen").unwrap(), BibleEnum::Genesis);
+        assert_eq!(parse_book("1 Sam").unwrap(), BibleEnum::FirstSamuel);
+        assert_eq!(parse_book("1Sa").unwrap(), BibleEnum::FirstSamuel);
+        assert_eq!(parse_book("Jn").unwrap(), BibleEnum::John);
+        assert_eq!(parse_book("1 Jn.").unwrap(), BibleEnum::FirstJohn);
+        assert_eq!(parse_book("PSA").unwrap(), BibleEnum::Psalms);
+    }
+
+    #[test]
+    fn test_ordinals() {
+        assert_eq!(parse_book("First Samuel").unwrap(), BibleEnum::FirstSamuel);
+        assert_eq!(parse_book("II Kings").unwrap(), BibleEnum::SecondKings);
+        assert_eq!(parse_book("3rd John").unwrap(), BibleEnum::ThirdJohn);
+        assert_eq!(parse_book("2nd Cor").unwrap(), BibleEnum::SecondCorinthians);
+        assert_eq!(parse_book("Isaiah").unwrap(), BibleEnum::Isaiah);
+    }
+
+    #[test]
+    fn test_french_and_prefixes() {
+        assert_eq!(parse_book("ésaïe").unwrap(), BibleEnum::Isaiah);
+        assert_eq!(parse_book("Premier Rois").unwrap(), BibleEnum::FirstKings);
+        assert_eq!(parse_book("Deut").unwrap(), BibleEnum::Deuteronomy);
+        assert_eq!(parse_book("Philip").unwrap(), BibleEnum::Philippians);
+    }
+
+    #[test]
+    fn test_unknown_or_ambiguous() {
+        assert!(parse_book("Hezekiah").is_err());
+        assert!(parse_book("").is_err());
+        // Judges, Judith and Jude
+        assert!(parse_book("Judi").is_ok());
+        assert!(parse_book("Ju").is_err());
+        // Philippians and Philemon
+        assert!(parse_book("Phi").is_err());
+    }
+}
@@ -1,9 +1,9 @@
 use serde::{Deserialize, Serialize};
-use strum::EnumIter;
+use strum::{EnumIter, FromRepr};
 
 macro_rules! make_bible_enum {
     ($error: ident ($message: literal), $enum_name: ident {$($book_ident: ident),* $(,)*}) => {
-        #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, EnumIter, Serialize, Deserialize)]
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, EnumIter, FromRepr, Serialize, Deserialize)]
         #[serde(rename_all = "kebab-case")]
         pub enum $enum_name {
             $($book_ident),*
@@ -12,16 +12,6 @@ macro_rules! make_bible_enum {
         #[derive(Debug, thiserror::Error)]
         #[error($message)]
         pub struct $error;
-
-        impl std::str::FromStr for $enum_name {
-            type Err = $error;
-            fn from_str(s: &str) -> Result<Self, Self::Err> {
-                match s {
-                    $(stringify!($book_ident) => Ok($enum_name::$book_ident),)*
-                    _ => Err($error),
-                }
-            }
-        }
     };
 }
 
@@ -94,5 +84,22 @@ make_bible_enum!(
         ThirdJohn,
         Jude,
         Revelation,
+        // Deuterocanonical books
+        Tobit,
+        Judith,
+        AdditionsToEsther,
+        WisdomOfSolomon,
+        Sirach,
+        Baruch,
+        PrayerOfAzariah,
+        Susanna,
+        BelAndTheDragon,
+        FirstMaccabees,
+        SecondMaccabees,
+        FirstEsdras,
+        PrayerOfManasseh,
+        Psalm151,
+        ThirdMaccabees,
+        FourthMaccabees,
     }
 );
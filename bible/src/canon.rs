@@ -0,0 +1,353 @@
+use serde::{Deserialize, Serialize};
+use strum::EnumIter;
+
+use crate::bible_enum::BibleEnum::{self, *};
+
+/// The lists of books received by the Christian traditions
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    Default,
+    EnumIter,
+    Serialize,
+    Deserialize,
+)]
+#[serde(rename_all = "kebab-case")]
+pub enum Canon {
+    /// The 66 books
+    #[default]
+    Protestant,
+    /// The 66 books with the deuterocanonical books
+    Catholic,
+    /// The Catholic canon with the books received by the Orthodox churches
+    Orthodox,
+}
+
+#[derive(Debug, thiserror::Error, PartialEq)]
+#[error("This canon is unknown: {0}")]
+pub struct CanonError(String);
+
+impl std::str::FromStr for Canon {
+    type Err = CanonError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "protestant" => Ok(Canon::Protestant),
+            "catholic" => Ok(Canon::Catholic),
+            "orthodox" => Ok(Canon::Orthodox),
+            _ => Err(CanonError(s.to_string())),
+        }
+    }
+}
+
+impl Canon {
+    /// The books of the canon, in reading order
+    pub fn books(&self) -> &'static [BibleEnum] {
+        match self {
+            Canon::Protestant => PROTESTANT_BOOKS,
+            Canon::Catholic => CATHOLIC_BOOKS,
+            Canon::Orthodox => ORTHODOX_BOOKS,
+        }
+    }
+
+    pub fn contains(&self, book: &BibleEnum) -> bool {
+        self.books().contains(book)
+    }
+
+    /// The index of the book in the reading order of the canon
+    pub fn position(&self, book: &BibleEnum) -> Option<usize> {
+        self.books().iter().position(|value| value == book)
+    }
+
+    pub fn first_book(&self) -> BibleEnum {
+        self.books()[0]
+    }
+
+    pub fn last_book(&self) -> BibleEnum {
+        self.books()[self.books().len() - 1]
+    }
+}
+
+const PROTESTANT_BOOKS: &[BibleEnum] = &[
+    Genesis,
+    Exodus,
+    Leviticus,
+    Numbers,
+    Deuteronomy,
+    Joshua,
+    Judges,
+    Ruth,
+    FirstSamuel,
+    SecondSamuel,
+    FirstKings,
+    SecondKings,
+    FirstChronicles,
+    SecondChronicles,
+    Ezra,
+    Nehemiah,
+    Esther,
+    Job,
+    Psalms,
+    Proverbs,
+    Ecclesiastes,
+    SongOfSolomon,
+    Isaiah,
+    Jeremiah,
+    Lamentations,
+    Ezekiel,
+    Daniel,
+    Hosea,
+    Joel,
+    Amos,
+    Obadiah,
+    Jonah,
+    Micah,
+    Nahum,
+    Habakkuk,
+    Zephaniah,
+    Haggai,
+    Zechariah,
+    Malachi,
+    Matthew,
+    Mark,
+    Luke,
+    John,
+    Acts,
+    Romans,
+    FirstCorinthians,
+    SecondCorinthians,
+    Galatians,
+    Ephesians,
+    Philippians,
+    Colossians,
+    FirstThessalonians,
+    SecondThessalonians,
+    FirstTimothy,
+    SecondTimothy,
+    Titus,
+    Philemon,
+    Hebrews,
+    James,
+    FirstPeter,
+    SecondPeter,
+    FirstJohn,
+    SecondJohn,
+    ThirdJohn,
+    Jude,
+    Revelation,
+];
+
+const CATHOLIC_BOOKS: &[BibleEnum] = &[
+    Genesis,
+    Exodus,
+    Leviticus,
+    Numbers,
+    Deuteronomy,
+    Joshua,
+    Judges,
+    Ruth,
+    FirstSamuel,
+    SecondSamuel,
+    FirstKings,
+    SecondKings,
+    FirstChronicles,
+    SecondChronicles,
+    Ezra,
+    Nehemiah,
+    Tobit,
+    Judith,
+    Esther,
+    AdditionsToEsther,
+    FirstMaccabees,
+    SecondMaccabees,
+    Job,
+    Psalms,
+    Proverbs,
+    Ecclesiastes,
+    SongOfSolomon,
+    WisdomOfSolomon,
+    Sirach,
+    Isaiah,
+    Jeremiah,
+    Lamentations,
+    Baruch,
+    Ezekiel,
+    Daniel,
+    PrayerOfAzariah,
+    Susanna,
+    BelAndTheDragon,
+    Hosea,
+    Joel,
+    Amos,
+    Obadiah,
+    Jonah,
+    Micah,
+    Nahum,
+    Habakkuk,
+    Zephaniah,
+    Haggai,
+    Zechariah,
+    Malachi,
+    Matthew,
+    Mark,
+    Luke,
+    John,
+    Acts,
+    Romans,
+    FirstCorinthians,
+    SecondCorinthians,
+    Galatians,
+    Ephesians,
+    Philippians,
+    Colossians,
+    FirstThessalonians,
+    SecondThessalonians,
+    FirstTimothy,
+    SecondTimothy,
+    Titus,
+    Philemon,
+    Hebrews,
+    James,
+    FirstPeter,
+    SecondPeter,
+    FirstJohn,
+    SecondJohn,
+    ThirdJohn,
+    Jude,
+    Revelation,
+];
+
+const ORTHODOX_BOOKS: &[BibleEnum] = &[
+    Genesis,
+    Exodus,
+    Leviticus,
+    Numbers,
+    Deuteronomy,
+    Joshua,
+    Judges,
+    Ruth,
+    FirstSamuel,
+    SecondSamuel,
+    FirstKings,
+    SecondKings,
+    FirstChronicles,
+    SecondChronicles,
+    PrayerOfManasseh,
+    FirstEsdras,
+    Ezra,
+    Nehemiah,
+    Tobit,
+    Judith,
+    Esther,
+    AdditionsToEsther,
+    FirstMaccabees,
+    SecondMaccabees,
+    ThirdMaccabees,
+    Psalms,
+    Psalm151,
+    Job,
+    Proverbs,
+    Ecclesiastes,
+    SongOfSolomon,
+    WisdomOfSolomon,
+    Sirach,
+    Hosea,
+    Amos,
+    Micah,
+    Joel,
+    Obadiah,
+    Jonah,
+    Nahum,
+    Habakkuk,
+    Zephaniah,
+    Haggai,
+    Zechariah,
+    Malachi,
+    Isaiah,
+    Jeremiah,
+    Baruch,
+    Lamentations,
+    Ezekiel,
+    Daniel,
+    PrayerOfAzariah,
+    Susanna,
+    BelAndTheDragon,
+    FourthMaccabees,
+    Matthew,
+    Mark,
+    Luke,
+    John,
+    Acts,
+    Romans,
+    FirstCorinthians,
+    SecondCorinthians,
+    Galatians,
+    Ephesians,
+    Philippians,
+    Colossians,
+    FirstThessalonians,
+    SecondThessalonians,
+    FirstTimothy,
+    SecondTimothy,
+    Titus,
+    Philemon,
+    Hebrews,
+    James,
+    FirstPeter,
+    SecondPeter,
+    FirstJohn,
+    SecondJohn,
+    ThirdJohn,
+    Jude,
+    Revelation,
+];
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeSet;
+
+    use strum::IntoEnumIterator;
+
+    use super::*;
+    use crate::BIBLE;
+
+    #[test]
+    fn test_protestant_follows_enum_order() {
+        let books: Vec<BibleEnum> = BibleEnum::iter().take(66).collect();
+
+        assert_eq!(Canon::Protestant.books(), books.as_slice());
+    }
+
+    #[test]
+    fn test_canons_include_each_other() {
+        let protestant: BTreeSet<&BibleEnum> = Canon::Protestant.books().iter().collect();
+        let catholic: BTreeSet<&BibleEnum> = Canon::Catholic.books().iter().collect();
+        let orthodox: BTreeSet<&BibleEnum> = Canon::Orthodox.books().iter().collect();
+
+        assert!(protestant.is_subset(&catholic));
+        assert!(catholic.is_subset(&orthodox));
+        assert_eq!(catholic.len(), Canon::Catholic.books().len());
+        assert_eq!(orthodox.len(), BibleEnum::iter().count());
+    }
+
+    #[test]
+    fn test_every_book_is_in_bible() {
+        for book in Canon::Orthodox.books() {
+            assert!((&BIBLE[book].chapters).into_iter().next().is_some());
+        }
+    }
+
+    #[test]
+    fn test_position() {
+        assert!(Canon::Catholic.contains(&Tobit));
+        assert!(!Canon::Protestant.contains(&Tobit));
+        assert_eq!(Canon::Catholic.position(&Tobit), Some(16));
+        assert_eq!(Canon::Orthodox.last_book(), Revelation);
+    }
+}
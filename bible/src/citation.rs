@@ -0,0 +1,327 @@
+use serde::{Deserialize, Serialize};
+use strum::EnumIter;
+
+use crate::{
+    bible_enum::BibleEnum,
+    constants::{OSIS_ID_LIST, SBL_ABBREVIATION_LIST, USFM_ID_LIST},
+    locale::Locale,
+    reference::ScriptureReference,
+    verse_ref::VerseRef,
+};
+
+/// The ways to write a reference
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    Default,
+    EnumIter,
+    Serialize,
+    Deserialize,
+)]
+#[serde(rename_all = "kebab-case")]
+pub enum CitationStyle {
+    /// The full book name of the locale, like "1 Samuel 3:10"
+    #[default]
+    Long,
+    /// The abbreviations of the SBL Handbook of Style, like "1 Sam 3:10"
+    Sbl,
+    /// The OSIS identifiers, like "1Sam.3.10"
+    Osis,
+    /// The USFM book codes, like "1SA 3:10"
+    Usfm,
+}
+
+#[derive(Debug, thiserror::Error, PartialEq)]
+#[error("This citation style is unknown: {0}")]
+pub struct CitationStyleError(String);
+
+impl std::str::FromStr for CitationStyle {
+    type Err = CitationStyleError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "long" | "full" => Ok(CitationStyle::Long),
+            "sbl" | "short" => Ok(CitationStyle::Sbl),
+            "osis" => Ok(CitationStyle::Osis),
+            "usfm" | "paratext" => Ok(CitationStyle::Usfm),
+            _ => Err(CitationStyleError(s.to_string())),
+        }
+    }
+}
+
+/// Writes verses and references in a citation style.
+///
+/// A compact formatter does not repeat in a range or a list the book and the
+/// chapter already written, like "John 3:16–18; 4:2". The OSIS style always
+/// writes whole identifiers, as its references must.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CitationFormatter {
+    style: CitationStyle,
+    locale: Locale,
+    compact: bool,
+}
+
+impl Default for CitationFormatter {
+    fn default() -> Self {
+        CitationFormatter::new(CitationStyle::default())
+    }
+}
+
+impl CitationFormatter {
+    pub fn new(style: CitationStyle) -> CitationFormatter {
+        CitationFormatter {
+            style,
+            locale: Locale::default(),
+            compact: true,
+        }
+    }
+
+    /// Use the book names of a locale for the long style
+    pub fn with_locale(mut self, locale: Locale) -> CitationFormatter {
+        self.locale = locale;
+        self
+    }
+
+    pub fn compact(mut self, compact: bool) -> CitationFormatter {
+        self.compact = compact;
+        self
+    }
+
+    pub fn style(&self) -> CitationStyle {
+        self.style
+    }
+
+    /// The name of a book in the style
+    pub fn book_name(&self, book: BibleEnum) -> &'static str {
+        match self.style {
+            CitationStyle::Long => book.localized_name(&self.locale),
+            CitationStyle::Sbl => SBL_ABBREVIATION_LIST[book as usize],
+            CitationStyle::Osis => OSIS_ID_LIST[book as usize],
+            CitationStyle::Usfm => USFM_ID_LIST[book as usize],
+        }
+    }
+
+    pub fn format_verse(&self, verse_ref: &VerseRef) -> String {
+        self.format_reference(&ScriptureReference::Verse(*verse_ref))
+    }
+
+    pub fn format_reference(&self, reference: &ScriptureReference) -> String {
+        if self.style == CitationStyle::Osis {
+            return self.osis(reference);
+        }
+
+        format!(
+            "{} {}",
+            self.book_name(reference.book()),
+            self.location(reference, true)
+        )
+    }
+
+    /// Write a list of references, like "John 3:16–18; Rom 8:28, 31"
+    pub fn format_list(&self, references: &[ScriptureReference]) -> String {
+        if self.style == CitationStyle::Osis {
+            return references
+                .iter()
+                .map(|reference| self.osis(reference))
+                .collect::<Vec<String>>()
+                .join(" ");
+        }
+
+        let mut text = String::new();
+        let mut previous: Option<&ScriptureReference> = None;
+
+        for reference in references {
+            match previous {
+                Some(previous) if self.compact && previous.book() == reference.book() => {
+                    match (verse_chapter(previous), verse_chapter(reference)) {
+                        (Some(previous_chapter), Some(chapter)) if previous_chapter == chapter => {
+                            text.push_str(", ");
+                            text.push_str(&self.location(reference, false));
+                        }
+                        (None, None) => {
+                            text.push_str(", ");
+                            text.push_str(&self.location(reference, true));
+                        }
+                        _ => {
+                            text.push_str("; ");
+                            text.push_str(&self.location(reference, true));
+                        }
+                    }
+                }
+                Some(_) => {
+                    text.push_str("; ");
+                    text.push_str(&self.format_reference(reference));
+                }
+                None => text.push_str(&self.format_reference(reference)),
+            }
+
+            previous = Some(reference);
+        }
+
+        text
+    }
+
+    fn dash(&self) -> char {
+        match self.style {
+            CitationStyle::Long | CitationStyle::Sbl => '–',
+            CitationStyle::Osis | CitationStyle::Usfm => '-',
+        }
+    }
+
+    /// The chapters and verses of a reference, without its first chapter when
+    /// the verses follow those of the previous reference
+    fn location(&self, reference: &ScriptureReference, with_chapter: bool) -> String {
+        let dash = self.dash();
+
+        match reference {
+            ScriptureReference::Chapter { chapter, .. } => u8::from(chapter).to_string(),
+            ScriptureReference::ChapterRange { first, last, .. } if self.compact => {
+                format!("{}{dash}{}", u8::from(first), u8::from(last))
+            }
+            ScriptureReference::ChapterRange { book, first, last } => format!(
+                "{}{dash}{} {}",
+                u8::from(first),
+                self.book_name(*book),
+                u8::from(last)
+            ),
+            ScriptureReference::Verse(verse_ref) => point(verse_ref, with_chapter),
+            ScriptureReference::VerseRange { first, last } => {
+                let end = if !self.compact {
+                    format!("{} {}", self.book_name(last.book), point(last, true))
+                } else {
+                    point(last, first.chapter != last.chapter)
+                };
+
+                format!("{}{dash}{end}", point(first, with_chapter))
+            }
+        }
+    }
+
+    fn osis(&self, reference: &ScriptureReference) -> String {
+        let book = self.book_name(reference.book());
+        let osis_verse = |verse_ref: &VerseRef| {
+            format!(
+                "{book}.{}.{}",
+                u8::from(verse_ref.chapter),
+                u8::from(verse_ref.verse)
+            )
+        };
+
+        match reference {
+            ScriptureReference::Chapter { chapter, .. } => format!("{book}.{}", u8::from(chapter)),
+            ScriptureReference::ChapterRange { first, last, .. } => {
+                format!("{book}.{}-{book}.{}", u8::from(first), u8::from(last))
+            }
+            ScriptureReference::Verse(verse_ref) => osis_verse(verse_ref),
+            ScriptureReference::VerseRange { first, last } => {
+                format!("{}-{}", osis_verse(first), osis_verse(last))
+            }
+        }
+    }
+}
+
+/// The chapter ending a reference to verses
+fn verse_chapter(reference: &ScriptureReference) -> Option<u8> {
+    match reference {
+        ScriptureReference::Verse(verse_ref) => Some(verse_ref.chapter.into()),
+        ScriptureReference::VerseRange { last, .. } => Some(last.chapter.into()),
+        _ => None,
+    }
+}
+
+fn point(verse_ref: &VerseRef, with_chapter: bool) -> String {
+    if with_chapter {
+        format!(
+            "{}:{}",
+            u8::from(verse_ref.chapter),
+            u8::from(verse_ref.verse)
+        )
+    } else {
+        u8::from(verse_ref.verse).to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn references(input: &str) -> Vec<ScriptureReference> {
+        ScriptureReference::parse_list(input).unwrap()
+    }
+
+    #[test]
+    fn test_styles() {
+        let reference = references("1 Sam 3:10")[0];
+
+        let formatted: Vec<String> = [
+            CitationStyle::Long,
+            CitationStyle::Sbl,
+            CitationStyle::Osis,
+            CitationStyle::Usfm,
+        ]
+        .iter()
+        .map(|style| CitationFormatter::new(*style).format_reference(&reference))
+        .collect();
+
+        assert_eq!(
+            formatted,
+            vec!["1 Samuel 3:10", "1 Sam 3:10", "1Sam.3.10", "1SA 3:10"]
+        );
+    }
+
+    #[test]
+    fn test_ranges() {
+        let formatter = CitationFormatter::new(CitationStyle::Long);
+
+        assert_eq!(
+            formatter.format_reference(&references("John 3:16-4:2")[0]),
+            "John 3:16–4:2"
+        );
+        assert_eq!(
+            formatter.format_reference(&references("John 3:16-18")[0]),
+            "John 3:16–18"
+        );
+        assert_eq!(
+            formatter
+                .compact(false)
+                .format_reference(&references("John 3:16-18")[0]),
+            "John 3:16–John 3:18"
+        );
+        assert_eq!(
+            CitationFormatter::new(CitationStyle::Osis).format_reference(&references("Gen 1-3")[0]),
+            "Gen.1-Gen.3"
+        );
+    }
+
+    #[test]
+    fn test_list() {
+        let list = references("John 3:16-18; 3:20; 4:2; Rom 8:28, 31; Ps 1, 2");
+
+        assert_eq!(
+            CitationFormatter::new(CitationStyle::Sbl).format_list(&list),
+            "John 3:16–18, 20; 4:2; Rom 8:28, 31; Ps 1, 2"
+        );
+        assert_eq!(
+            CitationFormatter::new(CitationStyle::Usfm)
+                .compact(false)
+                .format_list(&list[3..]),
+            "ROM 8:28; ROM 8:31; PSA 1; PSA 2"
+        );
+    }
+
+    #[test]
+    fn test_locale() {
+        let formatter = CitationFormatter::new(CitationStyle::Long).with_locale(Locale::French);
+
+        assert_eq!(
+            formatter.format_reference(&references("Rev 21:1-4")[0]),
+            "Apocalypse 21:1–4"
+        );
+    }
+}
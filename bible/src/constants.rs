@@ -65,4 +65,323 @@ pub const ENGLISH_BOOK_NAME_LIST: &[&str] = &[
     "3 John",
     "Jude",
     "Revelation",
+    "Tobit",
+    "Judith",
+    "Additions to Esther",
+    "Wisdom of Solomon",
+    "Sirach",
+    "Baruch",
+    "Prayer of Azariah",
+    "Susanna",
+    "Bel and the Dragon",
+    "1 Maccabees",
+    "2 Maccabees",
+    "1 Esdras",
+    "Prayer of Manasseh",
+    "Psalm 151",
+    "3 Maccabees",
+    "4 Maccabees",
+];
+
+pub const FRENCH_BOOK_NAME_LIST: &[&str] = &[
+    "Genèse",
+    "Exode",
+    "Lévitique",
+    "Nombres",
+    "Deutéronome",
+    "Josué",
+    "Juges",
+    "Ruth",
+    "1 Samuel",
+    "2 Samuel",
+    "1 Rois",
+    "2 Rois",
+    "1 Chroniques",
+    "2 Chroniques",
+    "Esdras",
+    "Néhémie",
+    "Esther",
+    "Job",
+    "Psaumes",
+    "Proverbes",
+    "Ecclésiaste",
+    "Cantique des Cantiques",
+    "Ésaïe",
+    "Jérémie",
+    "Lamentations",
+    "Ézéchiel",
+    "Daniel",
+    "Osée",
+    "Joël",
+    "Amos",
+    "Abdias",
+    "Jonas",
+    "Michée",
+    "Nahum",
+    "Habacuc",
+    "Sophonie",
+    "Aggée",
+    "Zacharie",
+    "Malachie",
+    "Matthieu",
+    "Marc",
+    "Luc",
+    "Jean",
+    "Actes",
+    "Romains",
+    "1 Corinthiens",
+    "2 Corinthiens",
+    "Galates",
+    "Éphésiens",
+    "Philippiens",
+    "Colossiens",
+    "1 Thessaloniciens",
+    "2 Thessaloniciens",
+    "1 Timothée",
+    "2 Timothée",
+    "Tite",
+    "Philémon",
+    "Hébreux",
+    "Jacques",
+    "1 Pierre",
+    "2 Pierre",
+    "1 Jean",
+    "2 Jean",
+    "3 Jean",
+    "Jude",
+    "Apocalypse",
+    "Tobie",
+    "Judith",
+    "Additions à Esther",
+    "Sagesse",
+    "Siracide",
+    "Baruch",
+    "Prière d'Azarias",
+    "Suzanne",
+    "Bel et le Dragon",
+    "1 Maccabées",
+    "2 Maccabées",
+    "1 Esdras",
+    "Prière de Manassé",
+    "Psaume 151",
+    "3 Maccabées",
+    "4 Maccabées",
+];
+
+pub const OSIS_ID_LIST: &[&str] = &[
+    "Gen", "Exod", "Lev", "Num", "Deut", "Josh", "Judg", "Ruth", "1Sam", "2Sam", "1Kgs", "2Kgs",
+    "1Chr", "2Chr", "Ezra", "Neh", "Esth", "Job", "Ps", "Prov", "Eccl", "Song", "Isa", "Jer",
+    "Lam", "Ezek", "Dan", "Hos", "Joel", "Amos", "Obad", "Jonah", "Mic", "Nah", "Hab", "Zeph",
+    "Hag", "Zech", "Mal", "Matt", "Mark", "Luke", "John", "Acts", "Rom", "1Cor", "2Cor", "Gal",
+    "Eph", "Phil", "Col", "1Thess", "2Thess", "1Tim", "2Tim", "Titus", "Phlm", "Heb", "Jas",
+    "1Pet", "2Pet", "1John", "2John", "3John", "Jude", "Rev", "Tob", "Jdt", "AddEsth", "Wis",
+    "Sir", "Bar", "PrAzar", "Sus", "Bel", "1Macc", "2Macc", "1Esd", "PrMan", "AddPs", "3Macc",
+    "4Macc",
+];
+
+pub const USFM_ID_LIST: &[&str] = &[
+    "GEN", "EXO", "LEV", "NUM", "DEU", "JOS", "JDG", "RUT", "1SA", "2SA", "1KI", "2KI", "1CH",
+    "2CH", "EZR", "NEH", "EST", "JOB", "PSA", "PRO", "ECC", "SNG", "ISA", "JER", "LAM", "EZK",
+    "DAN", "HOS", "JOL", "AMO", "OBA", "JON", "MIC", "NAM", "HAB", "ZEP", "HAG", "ZEC", "MAL",
+    "MAT", "MRK", "LUK", "JHN", "ACT", "ROM", "1CO", "2CO", "GAL", "EPH", "PHP", "COL", "1TH",
+    "2TH", "1TI", "2TI", "TIT", "PHM", "HEB", "JAS", "1PE", "2PE", "1JN", "2JN", "3JN", "JUD",
+    "REV", "TOB", "JDT", "ESG", "WIS", "SIR", "BAR", "S3Y", "SUS", "BEL", "1MA", "2MA", "1ES",
+    "MAN", "PS2", "3MA", "4MA",
+];
+
+/// The abbreviations of The SBL Handbook of Style
+pub const SBL_ABBREVIATION_LIST: &[&str] = &[
+    "Gen", "Exod", "Lev", "Num", "Deut", "Josh", "Judg", "Ruth", "1 Sam", "2 Sam", "1 Kgs",
+    "2 Kgs", "1 Chr", "2 Chr", "Ezra", "Neh", "Esth", "Job", "Ps", "Prov", "Eccl", "Song", "Isa",
+    "Jer", "Lam", "Ezek", "Dan", "Hos", "Joel", "Amos", "Obad", "Jonah", "Mic", "Nah", "Hab",
+    "Zeph", "Hag", "Zech", "Mal", "Matt", "Mark", "Luke", "John", "Acts", "Rom", "1 Cor", "2 Cor",
+    "Gal", "Eph", "Phil", "Col", "1 Thess", "2 Thess", "1 Tim", "2 Tim", "Titus", "Phlm", "Heb",
+    "Jas", "1 Pet", "2 Pet", "1 John", "2 John", "3 John", "Jude", "Rev", "Tob", "Jdt", "Add Esth",
+    "Wis", "Sir", "Bar", "Pr Azar", "Sus", "Bel", "1 Macc", "2 Macc", "1 Esd", "Pr Man", "Ps 151",
+    "3 Macc", "4 Macc",
+];
+
+/// Common abbreviations and other names of the books, in English and French
+pub const BOOK_ALIAS_LIST: &[&[&str]] = &[
+    &["Gen", "Ge", "Gn"],
+    &["Exod", "Exo", "Ex"],
+    &["Lev", "Le", "Lv"],
+    &["Num", "Nu", "Nm", "Nb"],
+    &["Deut", "De", "Dt"],
+    &["Josh", "Jos", "Jsh"],
+    &["Judg", "Jdg", "Jg", "Jdgs"],
+    &["Rth", "Ru", "Rt"],
+    &["1 Sam", "1 Sa", "1 Sm", "1 S"],
+    &["2 Sam", "2 Sa", "2 Sm", "2 S"],
+    &["1 Kgs", "1 Ki", "1 Kin", "1 K", "1 R"],
+    &["2 Kgs", "2 Ki", "2 Kin", "2 K", "2 R"],
+    &["1 Chr", "1 Chron", "1 Ch"],
+    &["2 Chr", "2 Chron", "2 Ch"],
+    &["Ezr", "Esd"],
+    &["Neh", "Ne"],
+    &["Esth", "Est"],
+    &["Jb"],
+    &["Ps", "Psa", "Psalm", "Pss", "Psm", "Psaume"],
+    &["Prov", "Pro", "Prv", "Pr"],
+    &["Eccl", "Ecc", "Ec", "Qoh", "Qo"],
+    &[
+        "Song",
+        "Sng",
+        "SoS",
+        "Song of Songs",
+        "Canticles",
+        "Ct",
+        "Cant",
+    ],
+    &["Isa", "Is"],
+    &["Jer", "Je", "Jr"],
+    &["Lam", "La"],
+    &["Ezek", "Eze", "Ezk", "Ez"],
+    &["Dan", "Da", "Dn"],
+    &["Hos", "Ho", "Os"],
+    &["Jl"],
+    &["Am"],
+    &["Obad", "Ob", "Ab"],
+    &["Jon", "Jnh"],
+    &["Mic", "Mi"],
+    &["Nah", "Na"],
+    &["Hab", "Hb"],
+    &["Zeph", "Zep", "Zp", "So"],
+    &["Hag", "Hg", "Ag"],
+    &["Zech", "Zec", "Zc", "Za"],
+    &["Mal", "Ml"],
+    &["Matt", "Mat", "Mt"],
+    &["Mrk", "Mk", "Mr"],
+    &["Luk", "Lk", "Lc"],
+    &["Jn", "Jhn", "Joh"],
+    &["Act", "Ac"],
+    &["Rom", "Ro", "Rm"],
+    &["1 Cor", "1 Co"],
+    &["2 Cor", "2 Co"],
+    &["Gal", "Ga"],
+    &["Eph", "Ephes", "Ep"],
+    &["Phil", "Php", "Pp", "Ph"],
+    &["Col"],
+    &["1 Thess", "1 Thes", "1 Th"],
+    &["2 Thess", "2 Thes", "2 Th"],
+    &["1 Tim", "1 Ti", "1 Tm"],
+    &["2 Tim", "2 Ti", "2 Tm"],
+    &["Tit"],
+    &["Philem", "Phm", "Phlm"],
+    &["Heb", "He"],
+    &["Jas", "Jm", "Jc"],
+    &["1 Pet", "1 Pe", "1 Pt", "1 P"],
+    &["2 Pet", "2 Pe", "2 Pt", "2 P"],
+    &["1 Jn", "1 Jhn", "1 Jo", "1 Joh"],
+    &["2 Jn", "2 Jhn", "2 Jo", "2 Joh"],
+    &["3 Jn", "3 Jhn", "3 Jo", "3 Joh"],
+    &["Jud", "Jd"],
+    &["Rev", "Re", "Rv", "Revelations", "Apoc", "Ap"],
+    &["Tob", "Tb"],
+    &["Jdt", "Jth"],
+    &["Add Esth", "Greek Esther", "Esther Greek"],
+    &["Wis", "Wisd", "Wisdom"],
+    &["Sir", "Ecclesiasticus", "Ecclus", "Si"],
+    &["Bar", "Ba"],
+    &[
+        "Pr Azar",
+        "Azariah",
+        "Song of Three",
+        "Song of the Three Holy Children",
+    ],
+    &["Sus"],
+    &["Bel and Dragon"],
+    &["1 Macc", "1 Mac", "1 Ma", "1 M"],
+    &["2 Macc", "2 Mac", "2 Ma", "2 M"],
+    &["1 Esd", "3 Esdras"],
+    &["Pr Man", "Prayer of Manasses", "Manasseh"],
+    &["Ps 151", "Add Ps"],
+    &["3 Macc", "3 Mac", "3 Ma", "3 M"],
+    &["4 Macc", "4 Mac", "4 Ma", "4 M"],
+];
+
+/// Other names of the books, from other traditions or older translations
+pub const ALTERNATIVE_NAME_LIST: &[&[&str]] = &[
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &["1 Kingdoms"],
+    &["2 Kingdoms"],
+    &["3 Kingdoms"],
+    &["4 Kingdoms"],
+    &["1 Paralipomenon"],
+    &["2 Paralipomenon"],
+    &[],
+    &[],
+    &[],
+    &[],
+    &["Psalter"],
+    &[],
+    &["Qoheleth"],
+    &["Song of Songs", "Canticle of Canticles"],
+    &["Isaias"],
+    &["Jeremias"],
+    &[],
+    &["Ezechiel"],
+    &[],
+    &["Osee"],
+    &[],
+    &[],
+    &["Abdias"],
+    &["Jonas"],
+    &["Micheas"],
+    &[],
+    &[],
+    &["Sophonias"],
+    &["Aggeus"],
+    &[],
+    &["Malachias"],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &["Apocalypse", "Revelation of John"],
+    &["Tobias"],
+    &[],
+    &["Greek Esther"],
+    &["Book of Wisdom"],
+    &["Ecclesiasticus", "Wisdom of Ben Sira"],
+    &[],
+    &["Song of the Three Holy Children"],
+    &[],
+    &[],
+    &[],
+    &[],
+    &["3 Esdras"],
+    &[],
+    &[],
+    &[],
+    &[],
 ];
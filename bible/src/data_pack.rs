@@ -0,0 +1,390 @@
+use std::{
+    collections::BTreeMap,
+    fs::OpenOptions,
+    io::{Read, Write},
+    path::{Path, PathBuf},
+};
+
+use book::{
+    book_components::{
+        chapter::Chapter,
+        chapter_number::{ChapterNumber, ChapterNumberError},
+        chapter_store::ChapterStore,
+        verse::{Verse, VerseError},
+    },
+    Book,
+};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+
+use crate::{bible_enum::BibleEnum, locale::Locale};
+
+pub const METADATA_FILE: &str = "metadata.json";
+pub const VERSIFICATION_FILE: &str = "versification.json";
+pub const TEXT_FILE: &str = "text.json";
+
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+pub struct PackMetadata {
+    /// The name of the translation
+    pub name: String,
+    /// The language code of the translation, like "en" or "fr", which gives the
+    /// names of the books
+    pub language: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub abbreviation: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub license: Option<String>,
+}
+
+/// The data needed to build a Bible: the number of verses of every chapter
+/// of every book, and optionally the text of the verses.
+///
+/// A verse count of zero marks a chapter missing from the book, like the
+/// first chapters of the Additions to Esther which start at chapter 10.
+///
+/// On disk a pack is a directory with a `metadata.json`, a `versification.json`
+/// and an optional `text.json`.
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+pub struct DataPack {
+    pub metadata: PackMetadata,
+    pub versification: BTreeMap<BibleEnum, Vec<u8>>,
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub text: BTreeMap<BibleEnum, Vec<Vec<String>>>,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum DataPackError {
+    #[error("Cannot access the pack file {path}")]
+    Io {
+        path: PathBuf,
+        #[source]
+        source: std::io::Error,
+    },
+    #[error("The pack file {path} is not valid JSON")]
+    Json {
+        path: PathBuf,
+        #[source]
+        source: serde_json::Error,
+    },
+    #[error("The pack has no book")]
+    NoBook,
+    #[error("{0:?} has no chapter")]
+    EmptyBook(BibleEnum),
+    #[error("{book:?} has an invalid chapter count")]
+    InvalidChapter {
+        book: BibleEnum,
+        #[source]
+        source: ChapterNumberError,
+    },
+    #[error("{book:?} {chapter} has an invalid verse count")]
+    InvalidVerseCount {
+        book: BibleEnum,
+        chapter: u8,
+        #[source]
+        source: VerseError,
+    },
+    #[error("{0:?} has a text but no versification")]
+    TextWithoutVersification(BibleEnum),
+    #[error("{0:?} has a text for more chapters than its versification")]
+    TextChapterMismatch(BibleEnum),
+    #[error("{book:?} {chapter} has {found} verses of text instead of {expected}")]
+    TextVerseMismatch {
+        book: BibleEnum,
+        chapter: u8,
+        expected: u8,
+        found: usize,
+    },
+}
+
+impl DataPack {
+    /// Read a pack from its directory
+    pub fn load(directory: &Path) -> Result<DataPack, DataPackError> {
+        let metadata: PackMetadata = read_json(&directory.join(METADATA_FILE))?;
+        let versification: BTreeMap<BibleEnum, Vec<u8>> =
+            read_json(&directory.join(VERSIFICATION_FILE))?;
+
+        let text_path = directory.join(TEXT_FILE);
+        let text: BTreeMap<BibleEnum, Vec<Vec<String>>> = if text_path.exists() {
+            read_json(&text_path)?
+        } else {
+            BTreeMap::new()
+        };
+
+        let pack = DataPack {
+            metadata,
+            versification,
+            text,
+        };
+        pack.validate()?;
+
+        Ok(pack)
+    }
+
+    /// Write the pack in a directory, creating it if needed
+    pub fn save(&self, directory: &Path) -> Result<(), DataPackError> {
+        std::fs::create_dir_all(directory).map_err(|source| DataPackError::Io {
+            path: directory.to_path_buf(),
+            source,
+        })?;
+
+        write_json(&directory.join(METADATA_FILE), &self.metadata)?;
+        write_json(&directory.join(VERSIFICATION_FILE), &self.versification)?;
+
+        if !self.text.is_empty() {
+            write_json(&directory.join(TEXT_FILE), &self.text)?;
+        }
+
+        Ok(())
+    }
+
+    /// Check that every count is a valid chapter or verse and that the text
+    /// fits the versification
+    pub fn validate(&self) -> Result<(), DataPackError> {
+        if self.versification.is_empty() {
+            return Err(DataPackError::NoBook);
+        }
+
+        for (book, verse_counts) in &self.versification {
+            if verse_counts.iter().all(|count| *count == 0) {
+                return Err(DataPackError::EmptyBook(*book));
+            }
+
+            u8::try_from(verse_counts.len())
+                .map_err(|_| ChapterNumberError::Max)
+                .and_then(ChapterNumber::try_from)
+                .map_err(|source| DataPackError::InvalidChapter {
+                    book: *book,
+                    source,
+                })?;
+
+            for (chapter, count) in (1u8..).zip(verse_counts).filter(|(_, count)| **count > 0) {
+                Verse::try_from(count).map_err(|source| DataPackError::InvalidVerseCount {
+                    book: *book,
+                    chapter,
+                    source,
+                })?;
+            }
+        }
+
+        for (book, chapters_text) in &self.text {
+            let verse_counts = self
+                .versification
+                .get(book)
+                .ok_or(DataPackError::TextWithoutVersification(*book))?;
+
+            if chapters_text.len() > verse_counts.len() {
+                return Err(DataPackError::TextChapterMismatch(*book));
+            }
+
+            for ((chapter, chapter_text), count) in (1u8..).zip(chapters_text).zip(verse_counts) {
+                if !chapter_text.is_empty() && chapter_text.len() != *count as usize {
+                    return Err(DataPackError::TextVerseMismatch {
+                        book: *book,
+                        chapter,
+                        expected: *count,
+                        found: chapter_text.len(),
+                    });
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Build the books of the pack
+    pub fn into_books(mut self) -> Result<BTreeMap<BibleEnum, Book>, DataPackError> {
+        self.validate()?;
+
+        let locale: Locale = self.metadata.language.parse().unwrap_or_default();
+
+        let mut books = BTreeMap::new();
+
+        for (book, verse_counts) in self.versification {
+            let mut chapter_store = ChapterStore::new();
+            let mut book_text = self.text.remove(&book).unwrap_or_default().into_iter();
+
+            for ((chapter, count), chapter_text) in (1u8..)
+                .zip(verse_counts)
+                .zip(book_text.by_ref().chain(std::iter::repeat_with(Vec::new)))
+            {
+                if count == 0 {
+                    continue;
+                }
+
+                chapter_store.add_chapter(Chapter::with_text(
+                    ChapterNumber::try_from(chapter)
+                        .map_err(|source| DataPackError::InvalidChapter { book, source })?,
+                    Verse::try_from(count).map_err(|source| DataPackError::InvalidVerseCount {
+                        book,
+                        chapter,
+                        source,
+                    })?,
+                    chapter_text,
+                ));
+            }
+
+            books.insert(
+                book,
+                Book {
+                    name: locale.book_name(&book),
+                    chapters: chapter_store,
+                },
+            );
+        }
+
+        Ok(books)
+    }
+}
+
+fn read_json<T: DeserializeOwned>(path: &Path) -> Result<T, DataPackError> {
+    let mut file =
+        OpenOptions::new()
+            .read(true)
+            .open(path)
+            .map_err(|source| DataPackError::Io {
+                path: path.to_path_buf(),
+                source,
+            })?;
+
+    let mut content = String::new();
+
+    file.read_to_string(&mut content)
+        .map_err(|source| DataPackError::Io {
+            path: path.to_path_buf(),
+            source,
+        })?;
+
+    serde_json::from_str(&content).map_err(|source| DataPackError::Json {
+        path: path.to_path_buf(),
+        source,
+    })
+}
+
+fn write_json<T: Serialize>(path: &Path, value: &T) -> Result<(), DataPackError> {
+    let data = serde_json::to_string(value).map_err(|source| DataPackError::Json {
+        path: path.to_path_buf(),
+        source,
+    })?;
+
+    let mut file = OpenOptions::new()
+        .write(true)
+        .create(true)
+        .truncate(true)
+        .open(path)
+        .map_err(|source| DataPackError::Io {
+            path: path.to_path_buf(),
+            source,
+        })?;
+
+    file.write_all(data.as_bytes())
+        .map_err(|source| DataPackError::Io {
+            path: path.to_path_buf(),
+            source,
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn small_pack() -> DataPack {
+        DataPack {
+            metadata: PackMetadata {
+                name: "Test Bible".to_string(),
+                language: "en".to_string(),
+                abbreviation: None,
+                license: None,
+            },
+            versification: BTreeMap::from([(BibleEnum::Jude, vec![2])]),
+            text: BTreeMap::from([(
+                BibleEnum::Jude,
+                vec![vec!["One".to_string(), "Two".to_string()]],
+            )]),
+        }
+    }
+
+    #[test]
+    fn test_save_and_load() {
+        let directory =
+            std::env::temp_dir().join(format!("bible_read_test_data_pack_{}", std::process::id()));
+        let pack = small_pack();
+
+        pack.save(&directory).unwrap();
+
+        assert_eq!(DataPack::load(&directory).unwrap(), pack);
+
+        std::fs::remove_dir_all(&directory).unwrap();
+    }
+
+    #[test]
+    fn test_into_books() {
+        let books = small_pack().into_books().unwrap();
+
+        let jude = &books[&BibleEnum::Jude];
+        assert_eq!(jude.name.to_string(), "Jude");
+        assert_eq!(
+            jude.chapters[ChapterNumber::try_from(1u8).unwrap()]
+                .get_verse_text(Verse::try_from(2u8).unwrap()),
+            Some("Two")
+        );
+    }
+
+    #[test]
+    fn test_book_names_in_pack_language() {
+        let mut pack = small_pack();
+        pack.metadata.language = "fr".to_string();
+        pack.versification.insert(BibleEnum::Genesis, vec![31]);
+
+        let books = pack.into_books().unwrap();
+        assert_eq!(books[&BibleEnum::Genesis].name.to_string(), "Genèse");
+    }
+
+    #[test]
+    fn test_invalid_verse_count() {
+        let mut pack = small_pack();
+        pack.versification.insert(BibleEnum::Ruth, vec![22, 177]);
+
+        let error = pack.validate().unwrap_err();
+        assert_eq!(error.to_string(), "Ruth 2 has an invalid verse count");
+    }
+
+    #[test]
+    fn test_missing_chapters() {
+        let mut pack = small_pack();
+        pack.versification.insert(BibleEnum::Ruth, vec![0, 23]);
+
+        let books = pack.clone().into_books().unwrap();
+        let chapters: Vec<u8> = books[&BibleEnum::Ruth]
+            .chapters
+            .into_iter()
+            .map(|chapter| chapter.get_chapter_number().into())
+            .collect();
+        assert_eq!(chapters, vec![2]);
+
+        pack.versification.insert(BibleEnum::Ruth, vec![0]);
+        assert_eq!(
+            pack.validate().unwrap_err().to_string(),
+            "Ruth has no chapter"
+        );
+    }
+
+    #[test]
+    fn test_text_mismatch() {
+        let mut pack = small_pack();
+        pack.versification.insert(BibleEnum::Jude, vec![3]);
+
+        let error = pack.validate().unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "Jude 1 has 2 verses of text instead of 3"
+        );
+    }
+
+    #[test]
+    fn test_text_without_versification() {
+        let mut pack = small_pack();
+        pack.text.insert(BibleEnum::Ruth, vec![]);
+
+        let error = pack.validate().unwrap_err();
+        assert_eq!(error.to_string(), "Ruth has a text but no versification");
+    }
+}
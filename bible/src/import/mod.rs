@@ -0,0 +1,638 @@
+//! Build data packs from the formats translations are published in.
+//!
+//! Every importer reads the verses of its sources into [`ImportedBook`]s, which
+//! [`Import::from_books`] turns into a [`DataPack`] and a report of the verses
+//! missing from the default versification, or beyond it.
+
+use std::{
+    collections::BTreeMap,
+    path::{Path, PathBuf},
+};
+
+use book::book_components::{
+    chapter_number::{ChapterNumber, ChapterNumberError},
+    verse::{Verse, VerseError},
+};
+
+use crate::{
+    bible_enum::BibleEnum,
+    data_pack::{DataPack, DataPackError, PackMetadata},
+    tables,
+    verse_ref::VerseRef,
+};
+
+pub mod osis;
+pub mod usfm;
+pub mod usx;
+
+/// The text of the verses of a book, by chapter then verse number
+#[derive(Debug, Clone, PartialEq)]
+pub struct ImportedBook {
+    pub book: BibleEnum,
+    pub chapters: BTreeMap<u8, BTreeMap<u8, String>>,
+}
+
+impl ImportedBook {
+    pub fn new(book: BibleEnum) -> ImportedBook {
+        ImportedBook {
+            book,
+            chapters: BTreeMap::new(),
+        }
+    }
+
+    /// Add a chapter, even if no verse of it is found
+    pub(crate) fn add_chapter(&mut self, chapter: u8) {
+        self.chapters.entry(chapter).or_default();
+    }
+
+    /// Add the text of a verse, whose whitespace is collapsed
+    pub(crate) fn add_verse(&mut self, chapter: u8, verse: u8, text: &str) {
+        self.chapters.entry(chapter).or_default().insert(
+            verse,
+            text.split_whitespace().collect::<Vec<&str>>().join(" "),
+        );
+    }
+
+    /// Every verse of the book
+    pub fn verses(&self) -> impl Iterator<Item = (u8, u8)> + '_ {
+        self.chapters
+            .iter()
+            .flat_map(|(chapter, verses)| verses.keys().map(|verse| (*chapter, *verse)))
+    }
+}
+
+/// How an import differs from the default versification
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct ImportReport {
+    /// The books imported, in the order of the Bible
+    pub books: Vec<BibleEnum>,
+    /// The verses of the default versification not found in the imported books
+    pub missing: Vec<VerseRef>,
+    /// The verses found beyond the default versification
+    pub extra: Vec<VerseRef>,
+    /// The files which are not of a known format
+    pub skipped: Vec<PathBuf>,
+}
+
+impl ImportReport {
+    /// Whether the imported books have exactly the verses of the default
+    /// versification
+    pub fn is_complete(&self) -> bool {
+        self.missing.is_empty() && self.extra.is_empty()
+    }
+}
+
+/// A data pack built from imported sources, with its report
+#[derive(Debug, Clone, PartialEq)]
+pub struct Import {
+    pub pack: DataPack,
+    pub report: ImportReport,
+}
+
+/// An error in the content of a source file
+#[derive(Debug, thiserror::Error, PartialEq)]
+pub enum ParseError {
+    #[error("The source has no book identifier")]
+    MissingBook,
+    #[error("The book identifier {0} is unknown")]
+    UnknownBook(String),
+    #[error("The chapter number {0} is invalid")]
+    InvalidChapter(String),
+    #[error("The verse number {0} is invalid")]
+    InvalidVerse(String),
+    #[error("The reference {0} is invalid")]
+    InvalidReference(String),
+    #[error("The verse {0} is outside of a chapter")]
+    VerseOutsideChapter(String),
+    #[error("The source is not valid XML: {0}")]
+    Xml(String),
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum ImportError {
+    #[error("Cannot read {path}")]
+    Io {
+        path: PathBuf,
+        #[source]
+        source: std::io::Error,
+    },
+    #[error("Cannot import {path}")]
+    Parse {
+        path: PathBuf,
+        #[source]
+        source: ParseError,
+    },
+    #[error("Cannot import {book:?}")]
+    Book {
+        book: BibleEnum,
+        #[source]
+        source: ParseError,
+    },
+    #[error("{0} is not of a known format")]
+    UnknownFormat(PathBuf),
+    #[error("{0:?} is in more than one source")]
+    DuplicateBook(BibleEnum),
+    #[error("No book was found")]
+    NoBook,
+    #[error(transparent)]
+    Pack(#[from] DataPackError),
+}
+
+/// The formats an importer can read
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SourceFormat {
+    Usfm,
+    Usx,
+    Osis,
+}
+
+impl SourceFormat {
+    /// The format of a file, from its extension
+    pub fn from_path(path: &Path) -> Option<SourceFormat> {
+        let extension = path.extension()?.to_str()?.to_lowercase();
+
+        match extension.as_str() {
+            "usfm" | "sfm" | "ptx" => Some(SourceFormat::Usfm),
+            "usx" => Some(SourceFormat::Usx),
+            "osis" => Some(SourceFormat::Osis),
+            _ => None,
+        }
+    }
+
+    /// The format of a file, from its extension or, for an XML file, from
+    /// its root element
+    pub fn from_source(path: &Path, source: &str) -> Option<SourceFormat> {
+        SourceFormat::from_path(path).or_else(|| {
+            let extension = path.extension()?.to_str()?;
+
+            if !extension.eq_ignore_ascii_case("xml") {
+                return None;
+            }
+
+            if source.contains("<osis") {
+                Some(SourceFormat::Osis)
+            } else if source.contains("<usx") {
+                Some(SourceFormat::Usx)
+            } else {
+                None
+            }
+        })
+    }
+
+    /// Read the books of a source, a single one except for OSIS
+    pub fn parse(&self, source: &str) -> Result<Vec<ImportedBook>, ParseError> {
+        match self {
+            SourceFormat::Usfm => usfm::parse(source).map(|book| vec![book]),
+            SourceFormat::Usx => usx::parse(source).map(|book| vec![book]),
+            SourceFormat::Osis => osis::parse(source),
+        }
+    }
+}
+
+impl Import {
+    /// Import a file, like an OSIS document holding a whole translation
+    pub fn from_file(path: &Path, metadata: PackMetadata) -> Result<Import, ImportError> {
+        let books = read_source(path)?.ok_or_else(|| ImportError::UnknownFormat(path.into()))?;
+
+        Import::from_books(metadata, books)
+    }
+
+    /// Import every USFM, USX and OSIS file of a directory
+    pub fn from_directory(directory: &Path, metadata: PackMetadata) -> Result<Import, ImportError> {
+        let mut paths: Vec<PathBuf> = std::fs::read_dir(directory)
+            .map_err(io_error(directory))?
+            .map(|entry| entry.map(|entry| entry.path()))
+            .collect::<Result<_, _>>()
+            .map_err(io_error(directory))?;
+        paths.sort();
+
+        let mut books: Vec<ImportedBook> = Vec::new();
+        let mut skipped: Vec<PathBuf> = Vec::new();
+
+        for path in paths.into_iter().filter(|path| path.is_file()) {
+            match read_source(&path)? {
+                Some(source_books) => books.extend(source_books),
+                None => skipped.push(path),
+            }
+        }
+
+        let mut import = Import::from_books(metadata, books)?;
+        import.report.skipped = skipped;
+
+        Ok(import)
+    }
+
+    /// Build a data pack from imported books, checked against the default
+    /// versification
+    pub fn from_books(
+        metadata: PackMetadata,
+        books: impl IntoIterator<Item = ImportedBook>,
+    ) -> Result<Import, ImportError> {
+        let mut imported: BTreeMap<BibleEnum, ImportedBook> = BTreeMap::new();
+
+        for book in books {
+            let book_enum: BibleEnum = book.book;
+            check_numbers(&book)?;
+
+            if imported.insert(book_enum, book).is_some() {
+                return Err(ImportError::DuplicateBook(book_enum));
+            }
+        }
+
+        if imported.is_empty() {
+            return Err(ImportError::NoBook);
+        }
+
+        let mut pack = DataPack {
+            metadata,
+            versification: BTreeMap::new(),
+            text: BTreeMap::new(),
+        };
+        let mut report = ImportReport::default();
+
+        for (book, content) in &imported {
+            let last_chapter: u8 = content.chapters.keys().next_back().copied().unwrap_or(0);
+            let mut verse_counts: Vec<u8> = vec![0; last_chapter as usize];
+            let mut text: Vec<Vec<String>> = vec![Vec::new(); last_chapter as usize];
+
+            for (chapter, verses) in &content.chapters {
+                let count: u8 = verses.keys().next_back().copied().unwrap_or(0);
+                let index = *chapter as usize - 1;
+
+                verse_counts[index] = count;
+                text[index] = (1..=count)
+                    .map(|verse| verses.get(&verse).cloned().unwrap_or_default())
+                    .collect();
+            }
+
+            pack.versification.insert(*book, verse_counts);
+            pack.text.insert(*book, text);
+
+            report.books.push(*book);
+            compare_with_default(content, &mut report);
+        }
+
+        pack.validate()?;
+
+        Ok(Import { pack, report })
+    }
+}
+
+/// Read the books of a file, or nothing if it is not of a known format
+fn read_source(path: &Path) -> Result<Option<Vec<ImportedBook>>, ImportError> {
+    // Only the extension is needed to skip most files, without reading them
+    let might_be_xml = path
+        .extension()
+        .and_then(|extension| extension.to_str())
+        .is_some_and(|extension| extension.eq_ignore_ascii_case("xml"));
+    if SourceFormat::from_path(path).is_none() && !might_be_xml {
+        return Ok(None);
+    }
+
+    let source = std::fs::read_to_string(path).map_err(io_error(path))?;
+    let Some(format) = SourceFormat::from_source(path, &source) else {
+        return Ok(None);
+    };
+
+    format
+        .parse(&source)
+        .map(Some)
+        .map_err(|source| ImportError::Parse {
+            path: path.to_path_buf(),
+            source,
+        })
+}
+
+fn io_error(path: &Path) -> impl FnOnce(std::io::Error) -> ImportError {
+    let path = path.to_path_buf();
+    move |source| ImportError::Io { path, source }
+}
+
+/// Check that the chapters and verses of a book, whose fields are public, are
+/// numbered from 1
+fn check_numbers(content: &ImportedBook) -> Result<(), ImportError> {
+    let error = if content.chapters.contains_key(&0) {
+        ParseError::InvalidChapter("0".to_string())
+    } else if content
+        .chapters
+        .values()
+        .any(|verses| verses.contains_key(&0))
+    {
+        ParseError::InvalidVerse("0".to_string())
+    } else {
+        return Ok(());
+    };
+
+    Err(ImportError::Book {
+        book: content.book,
+        source: error,
+    })
+}
+
+/// Report the verses of a book missing from the default versification, or
+/// beyond it
+fn compare_with_default(content: &ImportedBook, report: &mut ImportReport) {
+    let default_counts: &[u8] = tables::verse_counts(content.book);
+
+    for (chapter, count) in (1u8..).zip(default_counts) {
+        for verse in 1..=*count {
+            let found = content
+                .chapters
+                .get(&chapter)
+                .is_some_and(|verses| verses.contains_key(&verse));
+
+            if !found {
+                report
+                    .missing
+                    .extend(verse_ref(content.book, chapter, verse));
+            }
+        }
+    }
+
+    for (chapter, verse) in content.verses() {
+        let in_default = default_counts
+            .get(chapter as usize - 1)
+            .is_some_and(|count| verse <= *count);
+
+        if !in_default {
+            report.extra.extend(verse_ref(content.book, chapter, verse));
+        }
+    }
+}
+
+fn verse_ref(book: BibleEnum, chapter: u8, verse: u8) -> Option<VerseRef> {
+    Some(VerseRef::new(
+        book,
+        chapter.try_into().ok()?,
+        verse.try_into().ok()?,
+    ))
+}
+
+/// Read a chapter number, checked to be a valid chapter
+pub(crate) fn parse_chapter(value: &str) -> Result<u8, ParseError> {
+    value
+        .trim()
+        .parse::<u8>()
+        .map_err(|_| ChapterNumberError::Max)
+        .and_then(ChapterNumber::try_from)
+        .map(u8::from)
+        .map_err(|_| ParseError::InvalidChapter(value.to_string()))
+}
+
+/// Read a verse number, or the first and last numbers of a verse bridge like
+/// "4-6", ignoring segment letters like in "12a"
+pub(crate) fn parse_verses(value: &str) -> Result<(u8, u8), ParseError> {
+    let number = |part: &str| {
+        let digits: &str = part
+            .trim()
+            .trim_end_matches(|character: char| character.is_ascii_alphabetic());
+
+        digits
+            .parse::<u8>()
+            .map_err(|_| VerseError::Max)
+            .and_then(Verse::try_from)
+            .map(u8::from)
+            .map_err(|_| ParseError::InvalidVerse(value.to_string()))
+    };
+
+    match value.split_once(['-', ',']) {
+        Some((first, last)) => {
+            let (first, last) = (number(first)?, number(last)?);
+
+            if first > last {
+                return Err(ParseError::InvalidVerse(value.to_string()));
+            }
+
+            Ok((first, last))
+        }
+        None => number(value).map(|verse| (verse, verse)),
+    }
+}
+
+/// Whether a paragraph style holds no verse text, like a heading, a title or
+/// an introduction
+pub(crate) fn is_heading(style: &str) -> bool {
+    let style: &str = style.trim_end_matches(|character: char| character.is_ascii_digit());
+
+    matches!(
+        style,
+        "id" | "ide"
+            | "usfm"
+            | "h"
+            | "toc"
+            | "toca"
+            | "mt"
+            | "mte"
+            | "ms"
+            | "mr"
+            | "s"
+            | "sr"
+            | "r"
+            | "d"
+            | "sp"
+            | "rem"
+            | "sts"
+            | "cl"
+            | "cp"
+            | "cd"
+            | "lit"
+            | "restore"
+            | "imt"
+            | "imte"
+            | "is"
+            | "ip"
+            | "ipi"
+            | "im"
+            | "imi"
+            | "ipq"
+            | "imq"
+            | "ipr"
+            | "iq"
+            | "ib"
+            | "ili"
+            | "iot"
+            | "io"
+            | "iex"
+            | "ie"
+    )
+}
+
+/// Whether a character style holds a note, whose text is not part of a verse
+pub(crate) fn is_note(style: &str) -> bool {
+    matches!(style, "f" | "fe" | "ef" | "x" | "ex" | "fig")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    pub(crate) fn metadata() -> PackMetadata {
+        PackMetadata {
+            name: "Imported Bible".to_string(),
+            language: "en".to_string(),
+            abbreviation: None,
+            license: None,
+        }
+    }
+
+    #[test]
+    fn test_from_books() {
+        let mut jude = ImportedBook::new(BibleEnum::Jude);
+        jude.add_verse(1, 1, "Jude,  the servant");
+        jude.add_verse(1, 3, "Beloved");
+        jude.add_verse(1, 26, "An extra verse");
+
+        let import = Import::from_books(metadata(), [jude]).unwrap();
+
+        assert_eq!(import.pack.versification[&BibleEnum::Jude], vec![26]);
+        assert_eq!(
+            import.pack.text[&BibleEnum::Jude][0][0],
+            "Jude, the servant"
+        );
+        assert_eq!(import.pack.text[&BibleEnum::Jude][0][1], "");
+        assert_eq!(import.report.books, vec![BibleEnum::Jude]);
+        assert_eq!(import.report.missing.len(), 23);
+        assert_eq!(import.report.missing[0].to_string(), "Jude 1:2");
+        assert_eq!(import.report.extra.len(), 1);
+        assert_eq!(import.report.extra[0].to_string(), "Jude 1:26");
+        assert!(!import.report.is_complete());
+    }
+
+    #[test]
+    fn test_from_books_rejects_zero() {
+        let mut jude = ImportedBook::new(BibleEnum::Jude);
+        jude.chapters
+            .insert(0, BTreeMap::from([(1, "Jude".to_string())]));
+
+        assert!(matches!(
+            Import::from_books(metadata(), [jude]),
+            Err(ImportError::Book {
+                book: BibleEnum::Jude,
+                source: ParseError::InvalidChapter(_)
+            })
+        ));
+
+        let mut jude = ImportedBook::new(BibleEnum::Jude);
+        jude.add_verse(1, 0, "Jude");
+
+        assert!(matches!(
+            Import::from_books(metadata(), [jude]),
+            Err(ImportError::Book {
+                source: ParseError::InvalidVerse(_),
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn test_from_directory() {
+        let directory =
+            std::env::temp_dir().join(format!("bible_read_test_import_{}", std::process::id()));
+        std::fs::create_dir_all(&directory).unwrap();
+        std::fs::write(
+            directory.join("65JUD.SFM"),
+            "\\id JUD\n\\c 1\n\\p\n\\v 1 Jude, the servant of Jesus Christ\n",
+        )
+        .unwrap();
+        std::fs::write(
+            directory.join("57TIT.usx"),
+            r#"<usx><book code="TIT" style="id"/><chapter number="1" style="c"/>
+<para style="p"><verse number="1" style="v"/>Paul, a servant of God</para></usx>"#,
+        )
+        .unwrap();
+        std::fs::write(
+            directory.join("philemon.xml"),
+            r#"<osis><div type="book" osisID="Phlm"><chapter osisID="Phlm.1">
+<verse osisID="Phlm.1.1">Paul, a prisoner of Jesus Christ</verse></chapter></div></osis>"#,
+        )
+        .unwrap();
+        std::fs::write(directory.join("README.md"), "Test sources").unwrap();
+        std::fs::write(directory.join("settings.xml"), "<settings/>").unwrap();
+
+        let import = Import::from_directory(&directory, metadata()).unwrap();
+        let philemon = Import::from_file(&directory.join("philemon.xml"), metadata()).unwrap();
+        let unknown = Import::from_file(&directory.join("README.md"), metadata());
+        std::fs::remove_dir_all(&directory).unwrap();
+
+        assert_eq!(
+            import.report.books,
+            vec![BibleEnum::Titus, BibleEnum::Philemon, BibleEnum::Jude]
+        );
+        assert_eq!(
+            import.report.skipped,
+            vec![directory.join("README.md"), directory.join("settings.xml")]
+        );
+        assert_eq!(philemon.report.books, vec![BibleEnum::Philemon]);
+        assert!(matches!(unknown, Err(ImportError::UnknownFormat(_))));
+
+        let bible = crate::Bible::from_pack(import.pack).unwrap();
+        assert_eq!(
+            bible
+                .verse_text(
+                    BibleEnum::Titus,
+                    1u8.try_into().unwrap(),
+                    1u8.try_into().unwrap()
+                )
+                .unwrap(),
+            "Paul, a servant of God"
+        );
+    }
+
+    #[test]
+    fn test_errors() {
+        assert!(matches!(
+            Import::from_books(metadata(), []),
+            Err(ImportError::NoBook)
+        ));
+        assert!(matches!(
+            Import::from_books(
+                metadata(),
+                [
+                    ImportedBook::new(BibleEnum::Jude),
+                    ImportedBook::new(BibleEnum::Jude)
+                ]
+            ),
+            Err(ImportError::DuplicateBook(BibleEnum::Jude))
+        ));
+    }
+
+    #[test]
+    fn test_source_format() {
+        assert_eq!(
+            SourceFormat::from_path(Path::new("65JUD.SFM")),
+            Some(SourceFormat::Usfm)
+        );
+        assert_eq!(
+            SourceFormat::from_path(Path::new("kjv.osis")),
+            Some(SourceFormat::Osis)
+        );
+        assert_eq!(SourceFormat::from_path(Path::new("kjv.xml")), None);
+        assert_eq!(
+            SourceFormat::from_source(Path::new("kjv.xml"), "<?xml version=\"1.0\"?><osis>"),
+            Some(SourceFormat::Osis)
+        );
+        assert_eq!(
+            SourceFormat::from_source(Path::new("GEN.xml"), "<usx version=\"3.0\">"),
+            Some(SourceFormat::Usx)
+        );
+        assert_eq!(
+            SourceFormat::from_source(Path::new("notes.txt"), "<osis>"),
+            None
+        );
+    }
+
+    #[test]
+    fn test_parse_numbers() {
+        assert_eq!(parse_chapter("12"), Ok(12));
+        assert!(parse_chapter("151").is_err());
+        assert_eq!(parse_verses("4"), Ok((4, 4)));
+        assert_eq!(parse_verses("4-6"), Ok((4, 6)));
+        assert_eq!(parse_verses("12a"), Ok((12, 12)));
+        assert!(parse_verses("6-4").is_err());
+        assert!(parse_verses("0").is_err());
+        assert!(is_heading("s1"));
+        assert!(is_heading("toc2"));
+        assert!(!is_heading("p"));
+        assert!(!is_heading("q1"));
+    }
+}
@@ -0,0 +1,325 @@
+//! Read OSIS XML, which may hold every book of a translation in a file.
+//!
+//! Verses are found by their `osisID`, like "Gen.1.1", either as containers,
+//! `<verse osisID="Gen.1.1">…</verse>`, or as milestones, `<verse sID="…"
+//! osisID="Gen.1.1"/>` starting a verse and `<verse eID="…"/>` ending it.
+//! Headers, titles and notes are left out.
+
+use std::collections::BTreeMap;
+
+use quick_xml::{
+    events::{BytesStart, Event},
+    Reader,
+};
+
+use super::{
+    parse_chapter, parse_verses,
+    usx::{attribute, xml_error},
+    ImportedBook, ParseError,
+};
+use crate::bible_enum::BibleEnum;
+
+/// Read the verses of every book of an OSIS document, in the order of the
+/// Bible
+pub fn parse(source: &str) -> Result<Vec<ImportedBook>, ParseError> {
+    let mut reader = Reader::from_str(source);
+    let mut parser = Parser::default();
+    // The depth of the element being skipped, like a title or a note
+    let mut skipped_depth: usize = 0;
+
+    loop {
+        let event = reader.read_event().map_err(xml_error)?;
+
+        if skipped_depth > 0 {
+            match event {
+                Event::Start(_) => skipped_depth += 1,
+                Event::End(_) => skipped_depth -= 1,
+                Event::Eof => break,
+                _ => {}
+            }
+            continue;
+        }
+
+        match event {
+            Event::Start(element) if is_skipped(&element)? => skipped_depth = 1,
+            Event::Start(element) | Event::Empty(element) => parser.start_element(&element)?,
+            Event::End(element) => parser.end_element(element.name().as_ref()),
+            Event::Text(content) => parser.add_text(&content.unescape().map_err(xml_error)?),
+            Event::CData(content) => parser.add_text(&String::from_utf8_lossy(&content)),
+            Event::Eof => break,
+            _ => {}
+        }
+    }
+
+    parser.finish()
+}
+
+/// Whether an element holds no verse text, like the header, a title, a note
+/// or an introduction
+fn is_skipped(element: &BytesStart<'_>) -> Result<bool, ParseError> {
+    Ok(match element.name().as_ref() {
+        b"header" | b"title" | b"note" | b"figure" => true,
+        b"div" => matches!(
+            attribute(element, "type")?.as_deref(),
+            Some("introduction" | "preface" | "front" | "glossary")
+        ),
+        _ => false,
+    })
+}
+
+/// Whether an element separates words, like a paragraph or a line of poetry
+fn is_block(name: &[u8]) -> bool {
+    matches!(
+        name,
+        b"div" | b"chapter" | b"p" | b"l" | b"lg" | b"lb" | b"list" | b"item"
+    )
+}
+
+#[derive(Debug, Default)]
+struct Parser {
+    books: BTreeMap<BibleEnum, ImportedBook>,
+    /// The book, chapter, and first and last numbers of the verse being read
+    verse: Option<(BibleEnum, u8, u8, u8)>,
+    text: String,
+}
+
+impl Parser {
+    fn start_element(&mut self, element: &BytesStart<'_>) -> Result<(), ParseError> {
+        match element.name().as_ref() {
+            b"div" if attribute(element, "type")?.as_deref() == Some("book") => {
+                self.end_verse();
+
+                if let Some(id) = attribute(element, "osisID")? {
+                    let (book, _, _) = parse_id(&id)?;
+                    self.book_mut(book);
+                }
+            }
+            b"chapter" => {
+                self.end_verse();
+
+                if let Some(id) = attribute(element, "osisID")? {
+                    let (book, chapter, _) = parse_id(&id)?;
+                    let chapter = chapter.ok_or(ParseError::InvalidReference(id))?;
+                    self.book_mut(book).add_chapter(chapter);
+                }
+            }
+            b"verse" => {
+                self.end_verse();
+
+                // An end milestone has an eID, and no osisID
+                if attribute(element, "eID")?.is_none() {
+                    if let Some(id) = attribute(element, "osisID")? {
+                        self.verse = Some(parse_verse_ids(&id)?);
+                    }
+                }
+            }
+            _ => {}
+        }
+
+        if is_block(element.name().as_ref()) {
+            self.add_text(" ");
+        }
+
+        Ok(())
+    }
+
+    fn end_element(&mut self, name: &[u8]) {
+        if name == b"verse" {
+            self.end_verse();
+        } else if is_block(name) {
+            self.add_text(" ");
+        }
+    }
+
+    fn add_text(&mut self, text: &str) {
+        if self.verse.is_some() {
+            self.text.push_str(text);
+        }
+    }
+
+    fn book_mut(&mut self, book: BibleEnum) -> &mut ImportedBook {
+        self.books
+            .entry(book)
+            .or_insert_with(|| ImportedBook::new(book))
+    }
+
+    fn end_verse(&mut self) {
+        if let Some((book, chapter, first, last)) = self.verse.take() {
+            let text: String = std::mem::take(&mut self.text);
+            let book: &mut ImportedBook = self.book_mut(book);
+            book.add_verse(chapter, first, &text);
+
+            // The text of a verse bridge is given to its first verse
+            for verse in first + 1..=last {
+                book.add_verse(chapter, verse, "");
+            }
+        }
+
+        self.text.clear();
+    }
+
+    fn finish(mut self) -> Result<Vec<ImportedBook>, ParseError> {
+        self.end_verse();
+
+        if self.books.is_empty() {
+            return Err(ParseError::MissingBook);
+        }
+
+        Ok(self.books.into_values().collect())
+    }
+}
+
+/// Read an osisID like "Gen", "Gen.1" or "Gen.1.1", without its work prefix,
+/// like in "KJV:Gen.1.1", or its grain, like in "Gen.1.1!a"
+fn parse_id(id: &str) -> Result<(BibleEnum, Option<u8>, Option<u8>), ParseError> {
+    let reference: &str = id.rsplit_once(':').map_or(id, |(_, reference)| reference);
+    let reference: &str = reference
+        .split_once('!')
+        .map_or(reference, |(reference, _)| reference);
+
+    let mut parts = reference.split('.');
+    let book_id: &str = parts.next().unwrap_or_default();
+    let book = BibleEnum::from_osis_id(book_id)
+        .ok_or_else(|| ParseError::UnknownBook(book_id.to_string()))?;
+    let chapter: Option<u8> = parts.next().map(parse_chapter).transpose()?;
+    let verse: Option<u8> = parts
+        .next()
+        .map(|verse| parse_verses(verse).map(|(verse, _)| verse))
+        .transpose()?;
+
+    if parts.next().is_some() {
+        return Err(ParseError::InvalidReference(id.to_string()));
+    }
+
+    Ok((book, chapter, verse))
+}
+
+/// Read the osisID of a verse, which lists every verse of a bridge, like
+/// "Gen.1.1 Gen.1.2"
+fn parse_verse_ids(ids: &str) -> Result<(BibleEnum, u8, u8, u8), ParseError> {
+    let invalid = || ParseError::InvalidReference(ids.to_string());
+
+    let mut verses = ids.split_whitespace().map(|id| match parse_id(id)? {
+        (book, Some(chapter), Some(verse)) => Ok((book, chapter, verse)),
+        _ => Err(invalid()),
+    });
+
+    let (book, chapter, first) = verses.next().ok_or_else(invalid)??;
+    let last: u8 = match verses.next_back().transpose()? {
+        Some((last_book, last_chapter, last)) if (last_book, last_chapter) == (book, chapter) => {
+            last.max(first)
+        }
+        _ => first,
+    };
+
+    Ok((book, chapter, first, last))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MILESTONES: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<osis xmlns="http://www.bibletechnologies.net/2003/OSIS/namespace">
+<osisText osisIDWork="KJV" osisRefWork="Bible" xml:lang="en">
+<header><work osisWork="KJV"><title>King James Version</title></work></header>
+<div type="book" osisID="Jude">
+<title type="main">The General Epistle of Jude</title>
+<chapter sID="Jude.1" osisID="Jude.1"/>
+<verse sID="Jude.1.1" osisID="Jude.1.1"/>Jude, the <w lemma="strong:G1401">servant</w> of Jesus Christ,<note type="translation">Or slave.</note> and brother of James,<verse eID="Jude.1.1"/>
+<verse sID="Jude.1.2" osisID="Jude.1.2"/>Mercy unto you, and peace, and love, be multiplied.<verse eID="Jude.1.2"/>
+<title type="x-section">Judgment on False Teachers</title>
+<verse sID="Jude.1.3" osisID="KJV:Jude.1.3 KJV:Jude.1.4"/>Beloved, when I gave all diligence &amp; care<lb/>to write unto you.<verse eID="Jude.1.3"/>
+<chapter eID="Jude.1"/>
+</div>
+</osisText>
+</osis>
+"#;
+
+    const CONTAINERS: &str = r#"<osis><osisText>
+<div type="book" osisID="Gen"><chapter osisID="Gen.1">
+<verse osisID="Gen.1.1">In the beginning God created the heaven and the earth.</verse>
+<verse osisID="Gen.1.2">And the earth was without form,</verse>
+</chapter></div>
+<div type="book" osisID="Exod"><chapter osisID="Exod.1">
+<verse osisID="Exod.1.1"><l>Now these are the names</l><l>of the children of Israel</l></verse>
+</chapter><chapter osisID="Exod.2"/></div>
+</osisText></osis>"#;
+
+    #[test]
+    fn test_parse_milestones() {
+        let books = parse(MILESTONES).unwrap();
+        let chapter = &books[0].chapters[&1];
+
+        assert_eq!(books.len(), 1);
+        assert_eq!(books[0].book, BibleEnum::Jude);
+        assert_eq!(
+            chapter[&1],
+            "Jude, the servant of Jesus Christ, and brother of James,"
+        );
+        assert_eq!(
+            chapter[&2],
+            "Mercy unto you, and peace, and love, be multiplied."
+        );
+        assert_eq!(
+            chapter[&3],
+            "Beloved, when I gave all diligence & care to write unto you."
+        );
+        assert_eq!(chapter[&4], "");
+        assert_eq!(chapter.len(), 4);
+    }
+
+    #[test]
+    fn test_parse_containers() {
+        let books = parse(CONTAINERS).unwrap();
+
+        assert_eq!(
+            books.iter().map(|book| book.book).collect::<Vec<_>>(),
+            vec![BibleEnum::Genesis, BibleEnum::Exodus]
+        );
+        assert_eq!(books[0].chapters[&1][&2], "And the earth was without form,");
+        assert_eq!(
+            books[1].chapters[&1][&1],
+            "Now these are the names of the children of Israel"
+        );
+        assert!(books[1].chapters[&2].is_empty());
+    }
+
+    #[test]
+    fn test_parse_ids() {
+        assert_eq!(parse_id("Gen"), Ok((BibleEnum::Genesis, None, None)));
+        assert_eq!(
+            parse_id("KJV:1Cor.13.4!a"),
+            Ok((BibleEnum::FirstCorinthians, Some(13), Some(4)))
+        );
+        assert_eq!(
+            parse_verse_ids("Ps.3.0 Ps.3.1"),
+            Err(ParseError::InvalidVerse("0".to_string()))
+        );
+        assert_eq!(
+            parse_verse_ids("Rom.16.25 Rom.16.26 Rom.16.27"),
+            Ok((BibleEnum::Romans, 16, 25, 27))
+        );
+    }
+
+    #[test]
+    fn test_errors() {
+        assert_eq!(parse("<osis></osis>"), Err(ParseError::MissingBook));
+        assert_eq!(
+            parse(r#"<osis><verse osisID="Xyz.1.1"/></osis>"#),
+            Err(ParseError::UnknownBook("Xyz".to_string()))
+        );
+        assert_eq!(
+            parse(r#"<osis><verse osisID="Gen.1"/></osis>"#),
+            Err(ParseError::InvalidReference("Gen.1".to_string()))
+        );
+        assert_eq!(
+            parse(r#"<osis><chapter osisID="Gen.151"/></osis>"#),
+            Err(ParseError::InvalidChapter("151".to_string()))
+        );
+        assert!(matches!(
+            parse(r#"<osis><verse osisID="Gen.1.1"></osis>"#),
+            Err(ParseError::Xml(_))
+        ));
+    }
+}
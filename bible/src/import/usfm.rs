@@ -0,0 +1,280 @@
+//! Read USFM, the Unified Standard Format Markers of Paratext, a book per file.
+//!
+//! Only the verse text is kept: headings, introductions, footnotes and cross
+//! references are left out, character markers are removed and their
+//! attributes, like the Strong's numbers of `\w`, dropped.
+
+use super::{is_heading, is_note, parse_chapter, parse_verses, ImportedBook, ParseError};
+use crate::bible_enum::BibleEnum;
+
+/// Read the verses of a USFM book
+pub fn parse(source: &str) -> Result<ImportedBook, ParseError> {
+    let mut parser = Parser::default();
+
+    for line in source.lines() {
+        parser.read_line(line)?;
+    }
+
+    parser.finish()
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Token<'a> {
+    Marker { name: &'a str, closing: bool },
+    Text(&'a str),
+}
+
+/// Split a line into markers, like `\v` or `\wj*`, and the text between them
+fn tokenize(line: &str) -> Vec<Token<'_>> {
+    let mut tokens: Vec<Token<'_>> = Vec::new();
+    let mut rest: &str = line;
+
+    while !rest.is_empty() {
+        if let Some(after) = rest.strip_prefix('\\') {
+            let end: usize = after
+                .find(|character: char| {
+                    !(character.is_ascii_alphanumeric() || character == '+' || character == '-')
+                })
+                .unwrap_or(after.len());
+            let name: &str = after[..end].trim_start_matches('+');
+
+            rest = &after[end..];
+            let closing: bool = match rest.strip_prefix('*') {
+                Some(after_star) => {
+                    rest = after_star;
+                    true
+                }
+                None => {
+                    // The space after an opening marker is not part of the text
+                    rest = rest.strip_prefix([' ', '\t']).unwrap_or(rest);
+                    false
+                }
+            };
+
+            tokens.push(Token::Marker { name, closing });
+        } else {
+            let end: usize = rest.find('\\').unwrap_or(rest.len());
+            tokens.push(Token::Text(&rest[..end]));
+            rest = &rest[end..];
+        }
+    }
+
+    tokens
+}
+
+#[derive(Debug, Default)]
+struct Parser {
+    book: Option<ImportedBook>,
+    chapter: Option<u8>,
+    /// The first and last numbers of the verse being read
+    verses: Option<(u8, u8)>,
+    text: String,
+    /// The name of the note being skipped, until its closing marker
+    note: Option<String>,
+}
+
+impl Parser {
+    fn read_line(&mut self, line: &str) -> Result<(), ParseError> {
+        let tokens: Vec<Token<'_>> = tokenize(line.trim_start());
+        let mut tokens = tokens.into_iter().peekable();
+
+        if let Some(Token::Marker {
+            name,
+            closing: false,
+        }) = tokens.peek().copied()
+        {
+            if name == "id" {
+                return self.read_id(tokens.nth(1));
+            }
+
+            if is_heading(name) {
+                return Ok(());
+            }
+        }
+
+        while let Some(token) = tokens.next() {
+            if let Some(note) = &self.note {
+                if token
+                    == (Token::Marker {
+                        name: note,
+                        closing: true,
+                    })
+                {
+                    self.note = None;
+                }
+                continue;
+            }
+
+            match token {
+                Token::Marker {
+                    name,
+                    closing: false,
+                } if is_note(name) => self.note = Some(name.to_string()),
+                Token::Marker {
+                    name: "c",
+                    closing: false,
+                } => {
+                    let (number, text) = split_number(tokens.next());
+                    self.start_chapter(parse_chapter(number)?)?;
+                    self.add_text(text);
+                }
+                Token::Marker {
+                    name: "v",
+                    closing: false,
+                } => {
+                    let (number, text) = split_number(tokens.next());
+                    self.start_verse(number)?;
+                    self.add_text(text);
+                }
+                Token::Marker { .. } => {}
+                Token::Text(text) => self.add_text(text),
+            }
+        }
+
+        // A line break separates words
+        self.add_text(" ");
+
+        Ok(())
+    }
+
+    fn read_id(&mut self, token: Option<Token<'_>>) -> Result<(), ParseError> {
+        let Some(Token::Text(text)) = token else {
+            return Err(ParseError::MissingBook);
+        };
+        let code: &str = text.split_whitespace().next().unwrap_or_default();
+
+        let book = BibleEnum::from_usfm_id(code)
+            .ok_or_else(|| ParseError::UnknownBook(code.to_string()))?;
+        self.book = Some(ImportedBook::new(book));
+
+        Ok(())
+    }
+
+    fn start_chapter(&mut self, chapter: u8) -> Result<(), ParseError> {
+        self.end_verse();
+
+        self.book
+            .as_mut()
+            .ok_or(ParseError::MissingBook)?
+            .add_chapter(chapter);
+        self.chapter = Some(chapter);
+
+        Ok(())
+    }
+
+    fn start_verse(&mut self, number: &str) -> Result<(), ParseError> {
+        self.end_verse();
+
+        if self.chapter.is_none() {
+            return Err(ParseError::VerseOutsideChapter(number.to_string()));
+        }
+
+        self.verses = Some(parse_verses(number)?);
+
+        Ok(())
+    }
+
+    /// Add text to the verse being read, without the attributes of a
+    /// character marker
+    fn add_text(&mut self, text: &str) {
+        if self.verses.is_some() {
+            let text: &str = text.split_once('|').map_or(text, |(text, _)| text);
+            self.text.push_str(text);
+        }
+    }
+
+    fn end_verse(&mut self) {
+        if let (Some(book), Some(chapter), Some((first, last))) =
+            (self.book.as_mut(), self.chapter, self.verses.take())
+        {
+            book.add_verse(chapter, first, &self.text);
+
+            // The text of a verse bridge is given to its first verse
+            for verse in first + 1..=last {
+                book.add_verse(chapter, verse, "");
+            }
+        }
+
+        self.text.clear();
+    }
+
+    fn finish(mut self) -> Result<ImportedBook, ParseError> {
+        self.end_verse();
+
+        self.book.ok_or(ParseError::MissingBook)
+    }
+}
+
+/// Split the number following a `\c` or `\v` marker from the text after it
+fn split_number(token: Option<Token<'_>>) -> (&str, &str) {
+    match token {
+        Some(Token::Text(text)) => {
+            let text: &str = text.trim_start();
+            text.split_once(char::is_whitespace).unwrap_or((text, ""))
+        }
+        _ => ("", ""),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const JUDE: &str = r#"\id JUD Test Bible
+\h Jude
+\toc1 The General Epistle of Jude
+\mt1 The General Epistle of Jude
+\c 1
+\s1 Greeting
+\p
+\v 1 Jude, the \w servant|strong="G1401"\w* of Jesus Christ,\f + \fr 1:1 \ft Or slave.\f* and brother of James,
+to them that are sanctified by God the Father,
+\v 2 Mercy unto you, and \add peace\add*, and love, be multiplied.
+\s1 Judgment on False Teachers
+\p
+\v 3-4 Beloved, \wj when I gave\wj* all diligence\x - \xo 1:3 \xt Tit 1:4\x* to write unto you.
+"#;
+
+    #[test]
+    fn test_parse() {
+        let jude = parse(JUDE).unwrap();
+        let chapter = &jude.chapters[&1];
+
+        assert_eq!(jude.book, BibleEnum::Jude);
+        assert_eq!(
+            chapter[&1],
+            "Jude, the servant of Jesus Christ, and brother of James, to them that are sanctified by God the Father,"
+        );
+        assert_eq!(
+            chapter[&2],
+            "Mercy unto you, and peace, and love, be multiplied."
+        );
+        assert_eq!(
+            chapter[&3],
+            "Beloved, when I gave all diligence to write unto you."
+        );
+        assert_eq!(chapter[&4], "");
+        assert_eq!(chapter.len(), 4);
+    }
+
+    #[test]
+    fn test_errors() {
+        assert_eq!(parse("\\c 1\n\\v 1 Text"), Err(ParseError::MissingBook));
+        assert_eq!(
+            parse("\\id XYZ\n\\c 1"),
+            Err(ParseError::UnknownBook("XYZ".to_string()))
+        );
+        assert_eq!(
+            parse("\\id GEN\n\\v 1 Text"),
+            Err(ParseError::VerseOutsideChapter("1".to_string()))
+        );
+        assert_eq!(
+            parse("\\id GEN\n\\c 200"),
+            Err(ParseError::InvalidChapter("200".to_string()))
+        );
+        assert_eq!(
+            parse("\\id GEN\n\\c 1\n\\v x Text"),
+            Err(ParseError::InvalidVerse("x".to_string()))
+        );
+    }
+}
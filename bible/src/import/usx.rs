@@ -0,0 +1,218 @@
+//! Read USX, the XML form of USFM, a book per file.
+//!
+//! Verses are milestones, `<verse number="1"/>` starting a verse and, in USX 3,
+//! `<verse eid="GEN 1:1"/>` ending it. Headings, notes and figures are left out.
+
+use quick_xml::{
+    events::{BytesStart, Event},
+    Reader,
+};
+
+use super::{is_heading, is_note, parse_chapter, parse_verses, ImportedBook, ParseError};
+use crate::bible_enum::BibleEnum;
+
+/// Read the verses of a USX book
+pub fn parse(source: &str) -> Result<ImportedBook, ParseError> {
+    let mut reader = Reader::from_str(source);
+    let mut book: Option<ImportedBook> = None;
+    let mut chapter: Option<u8> = None;
+    let mut verses: Option<(u8, u8)> = None;
+    let mut text = String::new();
+    // The depth of the element being skipped, like a heading or a note
+    let mut skipped_depth: usize = 0;
+
+    loop {
+        let event = reader.read_event().map_err(xml_error)?;
+
+        if skipped_depth > 0 {
+            match event {
+                Event::Start(_) => skipped_depth += 1,
+                Event::End(_) => skipped_depth -= 1,
+                Event::Eof => break,
+                _ => {}
+            }
+            continue;
+        }
+
+        match event {
+            Event::Start(element) => match element.name().as_ref() {
+                b"book" => {
+                    let code = attribute(&element, "code")?.unwrap_or_default();
+                    book = Some(ImportedBook::new(
+                        BibleEnum::from_usfm_id(&code).ok_or(ParseError::UnknownBook(code))?,
+                    ));
+                    // The content of the book element is its name
+                    skipped_depth = 1;
+                }
+                b"para" if is_heading(&attribute(&element, "style")?.unwrap_or_default()) => {
+                    skipped_depth = 1;
+                }
+                b"note" | b"figure" | b"sidebar" => skipped_depth = 1,
+                b"char" if is_note(&attribute(&element, "style")?.unwrap_or_default()) => {
+                    skipped_depth = 1;
+                }
+                // A paragraph separates words
+                b"para" => text.push(' '),
+                _ => {}
+            },
+            Event::Empty(element) => match element.name().as_ref() {
+                b"book" => {
+                    let code = attribute(&element, "code")?.unwrap_or_default();
+                    book = Some(ImportedBook::new(
+                        BibleEnum::from_usfm_id(&code).ok_or(ParseError::UnknownBook(code))?,
+                    ));
+                }
+                b"chapter" => {
+                    if let Some(number) = attribute(&element, "number")? {
+                        end_verse(&mut book, chapter, &mut verses, &mut text);
+
+                        let number = parse_chapter(&number)?;
+                        book.as_mut()
+                            .ok_or(ParseError::MissingBook)?
+                            .add_chapter(number);
+                        chapter = Some(number);
+                    }
+                }
+                b"verse" => {
+                    end_verse(&mut book, chapter, &mut verses, &mut text);
+
+                    if let Some(number) = attribute(&element, "number")? {
+                        if chapter.is_none() {
+                            return Err(ParseError::VerseOutsideChapter(number));
+                        }
+
+                        verses = Some(parse_verses(&number)?);
+                    }
+                }
+                b"optbreak" => text.push(' '),
+                _ => {}
+            },
+            Event::End(element) if element.name().as_ref() == b"para" => text.push(' '),
+            Event::Text(content) if verses.is_some() => {
+                text.push_str(&content.unescape().map_err(xml_error)?);
+            }
+            Event::CData(content) if verses.is_some() => {
+                text.push_str(&String::from_utf8_lossy(&content));
+            }
+            Event::Eof => break,
+            _ => {}
+        }
+    }
+
+    end_verse(&mut book, chapter, &mut verses, &mut text);
+
+    book.ok_or(ParseError::MissingBook)
+}
+
+fn end_verse(
+    book: &mut Option<ImportedBook>,
+    chapter: Option<u8>,
+    verses: &mut Option<(u8, u8)>,
+    text: &mut String,
+) {
+    if let (Some(book), Some(chapter), Some((first, last))) =
+        (book.as_mut(), chapter, verses.take())
+    {
+        book.add_verse(chapter, first, text);
+
+        // The text of a verse bridge is given to its first verse
+        for verse in first + 1..=last {
+            book.add_verse(chapter, verse, "");
+        }
+    }
+
+    text.clear();
+}
+
+pub(super) fn attribute(
+    element: &BytesStart<'_>,
+    name: &str,
+) -> Result<Option<String>, ParseError> {
+    element
+        .try_get_attribute(name)
+        .map_err(xml_error)?
+        .map(|attribute| {
+            attribute
+                .unescape_value()
+                .map(|value| value.into_owned())
+                .map_err(xml_error)
+        })
+        .transpose()
+}
+
+pub(super) fn xml_error(error: impl std::fmt::Display) -> ParseError {
+    ParseError::Xml(error.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const JUDE: &str = r#"<?xml version="1.0" encoding="utf-8"?>
+<usx version="3.0">
+  <book code="JUD" style="id">Test Bible</book>
+  <para style="h">Jude</para>
+  <para style="mt1">The General Epistle of Jude</para>
+  <chapter number="1" style="c" sid="JUD 1" />
+  <para style="s1">Greeting</para>
+  <para style="p">
+    <verse number="1" style="v" sid="JUD 1:1" />Jude, the <char style="w" strong="G1401">servant</char> of Jesus Christ,<note caller="+" style="f"><char style="ft">Or slave.</char></note> and brother of James, to them that are sanctified by God the Father,<verse eid="JUD 1:1" />
+    <verse number="2" style="v" sid="JUD 1:2" />Mercy unto you, and peace, and love, be multiplied.<verse eid="JUD 1:2" /></para>
+  <para style="s1">Judgment on False Teachers</para>
+  <para style="p">
+    <verse number="3-4" style="v" sid="JUD 1:3-4" />Beloved, when I gave all diligence &amp; care to write unto you.<verse eid="JUD 1:3-4" /></para>
+  <chapter eid="JUD 1" />
+</usx>
+"#;
+
+    #[test]
+    fn test_parse() {
+        let jude = parse(JUDE).unwrap();
+        let chapter = &jude.chapters[&1];
+
+        assert_eq!(jude.book, BibleEnum::Jude);
+        assert_eq!(
+            chapter[&1],
+            "Jude, the servant of Jesus Christ, and brother of James, to them that are sanctified by God the Father,"
+        );
+        assert_eq!(
+            chapter[&2],
+            "Mercy unto you, and peace, and love, be multiplied."
+        );
+        assert_eq!(
+            chapter[&3],
+            "Beloved, when I gave all diligence & care to write unto you."
+        );
+        assert_eq!(chapter[&4], "");
+        assert_eq!(chapter.len(), 4);
+    }
+
+    #[test]
+    fn test_usx_2() {
+        // USX 2 has no end milestones, a verse ending at the next one
+        let source = r#"<usx version="2.5"><book code="GEN" style="id"/>
+<chapter number="1" style="c"/><para style="p"><verse number="1" style="v"/>In the beginning
+<verse number="2" style="v"/>And the earth</para></usx>"#;
+
+        let genesis = parse(source).unwrap();
+
+        assert_eq!(genesis.chapters[&1][&1], "In the beginning");
+        assert_eq!(genesis.chapters[&1][&2], "And the earth");
+    }
+
+    #[test]
+    fn test_errors() {
+        assert_eq!(
+            parse(r#"<usx><book code="XYZ" style="id"/></usx>"#),
+            Err(ParseError::UnknownBook("XYZ".to_string()))
+        );
+        assert_eq!(
+            parse(r#"<usx><chapter number="1" style="c"/></usx>"#),
+            Err(ParseError::MissingBook)
+        );
+        assert!(matches!(
+            parse(r#"<usx><book code="GEN"></usx>"#),
+            Err(ParseError::Xml(_))
+        ));
+    }
+}
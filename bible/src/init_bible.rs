@@ -1,140 +1,97 @@
 use book::{
-    book_components::{chapter::Chapter, chapter_store::ChapterStore},
+    book_components::{
+        chapter::Chapter, chapter_number::ChapterNumber, chapter_store::ChapterStore, verse::Verse,
+    },
     Book,
 };
-use serde::{Deserialize, Serialize};
 
-use crate::constants::ENGLISH_BOOK_NAME_LIST;
+use crate::{bible_enum::BibleEnum, data_pack::PackMetadata, locale::Locale, tables};
 
-macro_rules! make_bible_json_struct {
-    ($number_of_keys: expr, $struct_name: ident {$($book_id: ident),* $(,)*}) => {
-        #[derive(Debug, Deserialize, Serialize, Clone)]
-        struct $struct_name {
-            $($book_id: Vec<String>),*
-        }
+/// The metadata of the embedded Bible
+pub(crate) fn default_metadata() -> PackMetadata {
+    PackMetadata {
+        name: "King James Version".to_string(),
+        language: "en".to_string(),
+        abbreviation: Some("KJV".to_string()),
+        license: Some("Public Domain".to_string()),
+    }
+}
 
-        impl IntoIterator for $struct_name {
-            type Item = Vec<String>;
-            type IntoIter = std::array::IntoIter<Self::Item, $number_of_keys>;
+/// A book of the embedded Bible, without its text which is read from the
+/// static tables
+pub(crate) fn table_book(book: BibleEnum) -> Book {
+    let mut chapters = ChapterStore::new();
 
-            fn into_iter(self: $struct_name) -> Self::IntoIter {
-                [$(self.$book_id),*].into_iter()
-            }
+    for (chapter, count) in (1u8..).zip(tables::verse_counts(book)) {
+        if let (Ok(number), Ok(max_verse)) =
+            (ChapterNumber::try_from(chapter), Verse::try_from(*count))
+        {
+            chapters.add_chapter(Chapter::new(number, max_verse));
         }
     }
-}
 
-make_bible_json_struct!(
-    66usize,
-    BibleJson {
-        gen,
-        exo,
-        lev,
-        num,
-        deut,
-        jos,
-        judg,
-        ruth,
-        ones,
-        twos,
-        oner,
-        twor,
-        onech,
-        twoch,
-        esd,
-        neh,
-        esther,
-        job,
-        psa,
-        pro,
-        ecc,
-        song,
-        isa,
-        jerem,
-        lam,
-        eze,
-        dan,
-        os,
-        joel,
-        amos,
-        abd,
-        jona,
-        mic,
-        nah,
-        hab,
-        soph,
-        ag,
-        zach,
-        malac,
-        mat,
-        mark,
-        luke,
-        john,
-        acts,
-        rom,
-        oneco,
-        twoco,
-        gal,
-        eph,
-        philip,
-        col,
-        onethe,
-        twothe,
-        oneti,
-        twoti,
-        titus,
-        philemon,
-        heb,
-        jac,
-        onep,
-        twop,
-        onej,
-        twoj,
-        threej,
-        jude,
-        rev
+    Book {
+        name: Locale::English.book_name(&book),
+        chapters,
     }
-);
-
-fn get_bible_json() -> BibleJson {
-    let bible_str = include_str!("bible.json");
-    serde_json::from_str(bible_str).unwrap()
 }
 
-pub fn init_books() -> Vec<Book> {
-    let bible_json: BibleJson = get_bible_json();
-    let mut vec: Vec<Book> = Vec::new();
-    for (index_book, book) in bible_json.into_iter().enumerate() {
-        let mut chapter_store = ChapterStore::new();
+#[cfg(test)]
+mod tests {
+    use strum::IntoEnumIterator;
 
-        for (index, verse_str) in book.iter().enumerate() {
-            let verse: u8 = verse_str.parse().unwrap();
-            let chapter_number: u8 = index as u8 + 1u8;
+    use super::*;
+    use crate::data_pack::DataPack;
 
-            chapter_store.add_chapter(Chapter::new(
-                chapter_number.try_into().unwrap(),
-                verse.try_into().unwrap(),
-            ));
+    /// The embedded Bible as a data pack, whose validation checks the tables
+    fn default_pack() -> DataPack {
+        DataPack {
+            metadata: default_metadata(),
+            versification: tables::versification(),
+            text: tables::text(),
         }
+    }
+
+    #[test]
+    fn test_work() {
+        let pack = default_pack();
 
-        vec.push(Book {
-            name: ENGLISH_BOOK_NAME_LIST[index_book].try_into().unwrap(),
-            chapters: chapter_store,
-        });
+        assert_eq!(pack.versification[&BibleEnum::Genesis].first(), Some(&31));
     }
-    vec
-}
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+    #[test]
+    fn test_default_pack_is_valid() {
+        let pack = default_pack();
+
+        assert_eq!(pack.versification.len(), 82);
+        pack.validate().unwrap();
+    }
 
     #[test]
-    fn test_work() {
-        let mut data_iter = get_bible_json().into_iter();
+    fn test_default_pack_has_text() {
+        let pack = default_pack();
+
+        assert!(pack.text.contains_key(&BibleEnum::Genesis));
+    }
 
-        let gen_getted_by_iter = data_iter.next().unwrap();
+    #[test]
+    fn test_table_books_match_the_pack() {
+        let pack_books = default_pack().into_books().unwrap();
+
+        assert_eq!(BibleEnum::iter().count(), pack_books.len());
+        for book in BibleEnum::iter() {
+            let content = table_book(book);
+            let pack_book = &pack_books[&book];
 
-        assert_eq!(gen_getted_by_iter.first().unwrap(), "31");
+            assert_eq!(content.name, pack_book.name);
+            assert_eq!(
+                content.chapters.chapter_count(),
+                pack_book.chapters.chapter_count()
+            );
+            assert_eq!(
+                content.chapters.verse_count(),
+                pack_book.chapters.verse_count()
+            );
+        }
     }
 }
@@ -1,33 +1,76 @@
-use book::Book;
-use init_bible::init_books;
+use book::{
+    book_components::{
+        chapter::Chapter, chapter_number::ChapterNumber, chapter_store::ChapterStoreError,
+        verse::Verse,
+    },
+    Book,
+};
+use init_bible::{default_metadata, table_book};
 use lazy_static::lazy_static;
 use std::{
-    collections::btree_map::BTreeMap,
-    ops::{Index, IndexMut},
+    ops::{Bound, Index, RangeBounds},
+    sync::OnceLock,
 };
 use strum::IntoEnumIterator;
 
+pub mod alias;
 pub mod bible_enum;
+pub mod canon;
+pub mod citation;
 mod constants;
+pub mod data_pack;
+pub mod import;
 mod init_bible;
+pub mod locale;
+pub mod metadata;
+pub mod passage;
+pub mod reference;
+pub mod statistics;
+pub mod sword;
+pub mod tables;
+mod verse_index;
+pub mod verse_ref;
+pub mod versification;
 
 use bible_enum::BibleEnum;
+use canon::Canon;
+use data_pack::{DataPack, DataPackError, PackMetadata};
+use verse_index::VerseIndex;
+use verse_ref::VerseRef;
 
 #[derive(Debug)]
 pub struct Bible {
-    books: BTreeMap<BibleEnum, Book>,
+    metadata: PackMetadata,
+    /// The books in the order of `BibleEnum`, those of the embedded Bible being
+    /// built from the static tables when first used
+    books: Box<[BookEntry]>,
+    lookup: Lookup,
+}
+
+type BookEntry = (BibleEnum, OnceLock<Book>);
+
+/// The books of a Bible, in the order of `BibleEnum`
+pub type Books<'a> =
+    std::iter::Map<std::slice::Iter<'a, BookEntry>, fn(&'a BookEntry) -> (&'a BibleEnum, &'a Book)>;
+
+/// Where a Bible finds the positions and the text of its verses
+#[derive(Debug)]
+enum Lookup {
+    /// The static tables of the embedded Bible
+    Tables,
+    /// The index and the chapters of a Bible built from a data pack
+    Index(VerseIndex),
 }
 
 impl Default for Bible {
     fn default() -> Self {
-        let mut books = BTreeMap::new();
-        let data: Vec<Book> = init_books();
-
-        for (book, enum_value) in data.iter().zip(BibleEnum::iter()) {
-            books.insert(enum_value, book.clone());
+        Bible {
+            metadata: default_metadata(),
+            books: BibleEnum::iter()
+                .map(|book| (book, OnceLock::new()))
+                .collect(),
+            lookup: Lookup::Tables,
         }
-
-        Bible { books }
     }
 }
 
@@ -36,25 +79,287 @@ impl Bible {
         Default::default()
     }
 
-    pub fn range(
+    /// Build a Bible from a data pack
+    pub fn from_pack(pack: DataPack) -> Result<Bible, DataPackError> {
+        let metadata: PackMetadata = pack.metadata.clone();
+        let books = pack.into_books()?;
+
+        Ok(Bible {
+            metadata,
+            lookup: Lookup::Index(VerseIndex::new(&books)),
+            books: books
+                .into_iter()
+                .map(|(book, content)| (book, OnceLock::from(content)))
+                .collect(),
+        })
+    }
+
+    /// Build a Bible from a data pack directory
+    pub fn load_pack(directory: &std::path::Path) -> Result<Bible, DataPackError> {
+        Bible::from_pack(DataPack::load(directory)?)
+    }
+
+    /// Build a Bible from the books of a SWORD module
+    pub fn from_sword(module: &sword::SwordModule) -> Result<Bible, sword::SwordError> {
+        Ok(Bible::from_pack(module.to_pack()?)?)
+    }
+
+    pub fn metadata(&self) -> &PackMetadata {
+        &self.metadata
+    }
+
+    /// The books of the Bible
+    pub fn books(&self) -> Books<'_> {
+        self.books.iter().map(|entry| (&entry.0, load_book(entry)))
+    }
+
+    /// The books in a range, like `BibleEnum::Exodus..=BibleEnum::Numbers`
+    pub fn range<R: RangeBounds<BibleEnum>>(&self, range: R) -> Books<'_> {
+        let start: usize = self
+            .books
+            .partition_point(|(book, _)| match range.start_bound() {
+                Bound::Included(start) => book < start,
+                Bound::Excluded(start) => book <= start,
+                Bound::Unbounded => false,
+            });
+        let end: usize = self
+            .books
+            .partition_point(|(book, _)| match range.end_bound() {
+                Bound::Included(end) => book <= end,
+                Bound::Excluded(end) => book < end,
+                Bound::Unbounded => true,
+            });
+
+        self.books[start..end.max(start)]
+            .iter()
+            .map(|entry| (&entry.0, load_book(entry)))
+    }
+
+    /// The verses in a range, across chapters and books, like
+    /// `genesis_50_25..=exodus_1_2`
+    pub fn verses<R: RangeBounds<VerseRef>>(
+        &self,
+        range: R,
+    ) -> Result<impl DoubleEndedIterator<Item = VerseRef> + ExactSizeIterator + '_, BibleError>
+    {
+        let start: u32 = match range.start_bound() {
+            Bound::Included(verse_ref) => self.ordinal(verse_ref)?,
+            Bound::Excluded(verse_ref) => self.ordinal(verse_ref)? + 1,
+            Bound::Unbounded => 1,
+        };
+        let end: u32 = match range.end_bound() {
+            Bound::Included(verse_ref) => self.ordinal(verse_ref)? + 1,
+            Bound::Excluded(verse_ref) => self.ordinal(verse_ref)?,
+            Bound::Unbounded => self.verse_count() + 1,
+        };
+
+        Ok((start..end.max(start)).map(|ordinal| {
+            self.find_verse(ordinal)
+                .expect("The ordinals of the range are in the Bible")
+        }))
+    }
+
+    /// Get the text of a verse
+    pub fn verse_text(
+        &self,
+        book: BibleEnum,
+        chapter: ChapterNumber,
+        verse: Verse,
+    ) -> Result<&str, BibleError> {
+        let verse_ref = VerseRef::new(book, chapter, verse);
+        self.check_verse(&verse_ref)?;
+
+        match &self.lookup {
+            Lookup::Tables => tables::verse_text(&verse_ref),
+            Lookup::Index(_) => self.try_get_chapter(book, chapter)?.get_verse_text(verse),
+        }
+        .ok_or(BibleError::TextNotAvailable)
+    }
+
+    /// Get the text of every verse of a chapter, the first element being verse 1
+    pub fn chapter_text(
+        &self,
+        book: BibleEnum,
+        chapter: ChapterNumber,
+    ) -> Result<Vec<&str>, BibleError> {
+        let text: Vec<&str> = match &self.lookup {
+            Lookup::Tables => {
+                tables::max_verse(book, chapter).ok_or(BibleError::ChapterNotFound)?;
+                tables::chapter_text(book, chapter).to_vec()
+            }
+            Lookup::Index(_) => self
+                .try_get_chapter(book, chapter)?
+                .get_text()
+                .iter()
+                .map(String::as_str)
+                .collect(),
+        };
+
+        if text.is_empty() {
+            Err(BibleError::TextNotAvailable)
+        } else {
+            Ok(text)
+        }
+    }
+
+    /// Check that a verse is in the Bible
+    pub fn check_verse(&self, verse_ref: &VerseRef) -> Result<(), BibleError> {
+        let max_verse: Verse = match &self.lookup {
+            Lookup::Tables => tables::max_verse(verse_ref.book, verse_ref.chapter)
+                .ok_or(BibleError::ChapterNotFound)?,
+            Lookup::Index(_) => *self
+                .try_get_chapter(verse_ref.book, verse_ref.chapter)?
+                .get_max_verse(),
+        };
+
+        if verse_ref.verse > max_verse {
+            return Err(BibleError::VerseNotFound);
+        }
+
+        Ok(())
+    }
+
+    /// The verse after a verse, in the next chapter or book if needed
+    pub fn next_verse(&self, verse_ref: &VerseRef) -> Option<VerseRef> {
+        self.find_verse(self.find_ordinal(verse_ref)? + 1)
+    }
+
+    /// The verse before a verse, in the previous chapter or book if needed
+    pub fn previous_verse(&self, verse_ref: &VerseRef) -> Option<VerseRef> {
+        self.find_verse(self.find_ordinal(verse_ref)? - 1)
+    }
+
+    /// The number of verses of the Bible
+    pub fn verse_count(&self) -> u32 {
+        match &self.lookup {
+            Lookup::Tables => tables::verse_total(),
+            Lookup::Index(index) => index.len(),
+        }
+    }
+
+    /// The position of a verse in the Bible, the first verse being 1
+    pub fn ordinal(&self, verse_ref: &VerseRef) -> Result<u32, BibleError> {
+        self.find_ordinal(verse_ref).ok_or_else(|| {
+            self.check_verse(verse_ref)
+                .err()
+                .unwrap_or(BibleError::VerseNotFound)
+        })
+    }
+
+    /// The verse at a position in the Bible, the first verse being 1
+    pub fn verse_at(&self, ordinal: u32) -> Result<VerseRef, BibleError> {
+        self.find_verse(ordinal)
+            .ok_or(BibleError::OrdinalNotFound(ordinal))
+    }
+
+    fn find_ordinal(&self, verse_ref: &VerseRef) -> Option<u32> {
+        match &self.lookup {
+            Lookup::Tables => tables::ordinal(verse_ref),
+            Lookup::Index(index) => index.ordinal(verse_ref),
+        }
+    }
+
+    fn find_verse(&self, ordinal: u32) -> Option<VerseRef> {
+        match &self.lookup {
+            Lookup::Tables => tables::verse_at(ordinal),
+            Lookup::Index(index) => index.verse(ordinal),
+        }
+    }
+
+    /// The number of verses to go from a verse to another, negative when going
+    /// backward
+    pub fn verses_between(&self, from: &VerseRef, to: &VerseRef) -> Result<i64, BibleError> {
+        Ok(self.ordinal(to)? as i64 - self.ordinal(from)? as i64)
+    }
+
+    /// The share of the 66 books read up to a verse included, between 0 and 1
+    pub fn progress(&self, verse_ref: &VerseRef) -> Result<f64, BibleError> {
+        self.canon_progress(verse_ref, &Canon::default())
+    }
+
+    /// The share of the books of a canon read up to a verse included, in the
+    /// reading order of the canon, between 0 and 1
+    pub fn canon_progress(&self, verse_ref: &VerseRef, canon: &Canon) -> Result<f64, BibleError> {
+        self.check_verse(verse_ref)?;
+        let position: usize = canon
+            .position(&verse_ref.book)
+            .ok_or(BibleError::BookNotInCanon)?;
+
+        let book_verses = |book: &BibleEnum| -> u32 {
+            self.get(*book)
+                .map_or(0, |content| content.chapters.verse_count())
+        };
+        let read: u32 = canon.books()[..position]
+            .iter()
+            .map(book_verses)
+            .sum::<u32>()
+            + self
+                .try_get(verse_ref.book)?
+                .chapters
+                .range(..verse_ref.chapter)
+                .map(|(_, chapter)| u8::from(chapter.get_max_verse()) as u32)
+                .sum::<u32>()
+            + u8::from(verse_ref.verse) as u32;
+        let total: u32 = canon.books().iter().map(book_verses).sum();
+
+        Ok(read as f64 / total as f64)
+    }
+
+    /// Get a book, if the Bible has it
+    pub fn get(&self, book: BibleEnum) -> Option<&Book> {
+        self.books
+            .binary_search_by_key(&book, |(book, _)| *book)
+            .ok()
+            .map(|position| load_book(&self.books[position]))
+    }
+
+    /// Get a book, a Bible from a data pack missing some books
+    pub fn try_get(&self, book: BibleEnum) -> Result<&Book, BibleError> {
+        self.get(book).ok_or(BibleError::BookNotFound)
+    }
+
+    /// Get a chapter of a book
+    pub fn try_get_chapter(
         &self,
-        range: core::ops::Range<BibleEnum>,
-    ) -> std::collections::btree_map::Range<BibleEnum, Book> {
-        self.books.range(range)
+        book: BibleEnum,
+        chapter: ChapterNumber,
+    ) -> Result<&Chapter, BibleError> {
+        Ok(self.try_get(book)?.chapters.try_get(chapter)?)
     }
 }
 
-#[derive(Debug, thiserror::Error)]
+#[derive(Debug, thiserror::Error, PartialEq)]
 pub enum BibleError {
     #[error("Book not found in Bible")]
     BookNotFound,
+    #[error("Chapter not found in book")]
+    ChapterNotFound,
+    #[error("Verse not found in chapter")]
+    VerseNotFound,
+    #[error("The text of this passage is not available")]
+    TextNotAvailable,
+    #[error("There is no verse {0} in the Bible")]
+    OrdinalNotFound(u32),
+    #[error("Book not in the canon")]
+    BookNotInCanon,
+}
+
+impl From<ChapterStoreError> for BibleError {
+    fn from(error: ChapterStoreError) -> Self {
+        match error {
+            ChapterStoreError::ChapterNotFound(_) => BibleError::ChapterNotFound,
+        }
+    }
 }
 
+/// Panics if the book is not in the Bible, [`Bible::try_get`] being the
+/// fallible access
 impl Index<BibleEnum> for Bible {
     type Output = Book;
 
     fn index(&self, index: BibleEnum) -> &Self::Output {
-        self.books.get(&index).unwrap()
+        self.try_get(index)
+            .unwrap_or_else(|error| panic!("{index:?}: {error}"))
     }
 }
 
@@ -66,27 +371,21 @@ impl Index<&BibleEnum> for Bible {
     }
 }
 
-impl IndexMut<BibleEnum> for Bible {
-    fn index_mut(&mut self, index: BibleEnum) -> &mut Self::Output {
-        self.books.get_mut(&index).unwrap()
-    }
-}
-
-impl IndexMut<&BibleEnum> for Bible {
-    fn index_mut(&mut self, index: &BibleEnum) -> &mut Self::Output {
-        &mut self[*index]
-    }
-}
-
 impl<'a> IntoIterator for &'a Bible {
     type Item = &'a Book;
-    type IntoIter = std::collections::btree_map::Values<'a, BibleEnum, Book>;
+    type IntoIter = std::iter::Map<std::slice::Iter<'a, BookEntry>, fn(&'a BookEntry) -> &'a Book>;
 
     fn into_iter(self) -> Self::IntoIter {
-        self.books.values()
+        self.books.iter().map(load_book)
     }
 }
 
+/// A book, built from the static tables the first time it is used in the
+/// embedded Bible
+fn load_book((book, content): &BookEntry) -> &Book {
+    content.get_or_init(|| table_book(*book))
+}
+
 lazy_static! {
     pub static ref BIBLE: Bible = Bible::new();
 }
@@ -94,6 +393,7 @@ lazy_static! {
 #[cfg(test)]
 mod tests {
     use book::book_components::{chapter_number::ChapterNumber, verse::Verse};
+    use std::collections::BTreeMap;
 
     use super::*;
 
@@ -111,16 +411,226 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_books_are_built_when_used() {
+        let bible: Bible = Bible::new();
+        let built = |bible: &Bible| {
+            bible
+                .books
+                .iter()
+                .filter(|(_, content)| content.get().is_some())
+                .count()
+        };
+
+        assert_eq!(built(&bible), 0);
+        assert_eq!(bible[BibleEnum::John].chapter_count(), 21);
+        assert_eq!(built(&bible), 1);
+        assert_eq!(bible.books().count(), 82);
+        assert_eq!(built(&bible), 82);
+    }
+
+    #[test]
+    fn test_try_get() {
+        let bible = Bible::from_pack(DataPack {
+            metadata: PackMetadata {
+                name: "Test Bible".to_string(),
+                language: "en".to_string(),
+                abbreviation: None,
+                license: None,
+            },
+            versification: BTreeMap::from([(BibleEnum::Jude, vec![25])]),
+            text: BTreeMap::new(),
+        })
+        .unwrap();
+
+        assert!(bible.try_get(BibleEnum::Jude).is_ok());
+        assert!(bible.get(BibleEnum::Genesis).is_none());
+        assert_eq!(
+            bible.try_get(BibleEnum::Genesis).unwrap_err(),
+            BibleError::BookNotFound
+        );
+        assert_eq!(
+            bible
+                .try_get_chapter(BibleEnum::Jude, 2u8.try_into().unwrap())
+                .unwrap_err(),
+            BibleError::ChapterNotFound
+        );
+    }
+
+    #[test]
+    fn test_ordinal() {
+        let bible: Bible = Bible::new();
+        let verse = |book: BibleEnum, chapter: u8, verse: u8| {
+            VerseRef::new(book, chapter.try_into().unwrap(), verse.try_into().unwrap())
+        };
+
+        assert_eq!(bible.ordinal(&verse(BibleEnum::Genesis, 1, 1)), Ok(1));
+        assert_eq!(bible.ordinal(&verse(BibleEnum::Genesis, 2, 1)), Ok(32));
+        assert_eq!(
+            bible.ordinal(&verse(BibleEnum::Revelation, 22, 21)),
+            Ok(31102)
+        );
+        assert_eq!(bible.verse_at(31103), Ok(verse(BibleEnum::Tobit, 1, 1)));
+        assert_eq!(bible.verse_at(0), Err(BibleError::OrdinalNotFound(0)));
+        assert_eq!(
+            bible.ordinal(&verse(BibleEnum::Genesis, 1, 32)),
+            Err(BibleError::VerseNotFound)
+        );
+        assert_eq!(
+            bible.ordinal(&verse(BibleEnum::Genesis, 51, 1)),
+            Err(BibleError::ChapterNotFound)
+        );
+
+        for ordinal in 1..=bible.verse_count() {
+            assert_eq!(
+                bible.ordinal(&bible.verse_at(ordinal).unwrap()),
+                Ok(ordinal)
+            );
+        }
+    }
+
+    #[test]
+    fn test_verses_between() {
+        let bible: Bible = Bible::new();
+        let genesis_end = VerseRef::new(
+            BibleEnum::Genesis,
+            50u8.try_into().unwrap(),
+            26u8.try_into().unwrap(),
+        );
+        let exodus_start = VerseRef::new(
+            BibleEnum::Exodus,
+            1u8.try_into().unwrap(),
+            1u8.try_into().unwrap(),
+        );
+
+        assert_eq!(bible.verses_between(&genesis_end, &exodus_start), Ok(1));
+        assert_eq!(bible.verses_between(&exodus_start, &genesis_end), Ok(-1));
+        assert_eq!(bible.next_verse(&genesis_end), Some(exodus_start));
+        assert_eq!(bible.previous_verse(&exodus_start), Some(genesis_end));
+        assert_eq!(bible.progress(&genesis_end), Ok(1533.0 / 31102.0));
+    }
+
+    #[test]
+    fn test_progress() {
+        let bible: Bible = Bible::new();
+        let verse = |book: BibleEnum, chapter: u8, verse: u8| {
+            VerseRef::new(book, chapter.try_into().unwrap(), verse.try_into().unwrap())
+        };
+        let revelation_end = verse(BibleEnum::Revelation, 22, 21);
+
+        assert_eq!(bible.progress(&revelation_end), Ok(1.0));
+        assert_eq!(
+            bible.progress(&verse(BibleEnum::Tobit, 1, 1)),
+            Err(BibleError::BookNotInCanon)
+        );
+        assert_eq!(
+            bible.progress(&verse(BibleEnum::Genesis, 1, 32)),
+            Err(BibleError::VerseNotFound)
+        );
+
+        // Revelation comes last in the reading order of every canon
+        for canon in [Canon::Catholic, Canon::Orthodox] {
+            assert_eq!(bible.canon_progress(&revelation_end, &canon), Ok(1.0));
+            assert!(
+                bible
+                    .canon_progress(&verse(BibleEnum::Tobit, 1, 1), &canon)
+                    .unwrap()
+                    < 0.5
+            );
+        }
+    }
+
+    #[test]
+    fn test_verse_text() {
+        let bible: Bible = Bible::new();
+
+        assert_eq!(
+            bible
+                .verse_text(
+                    BibleEnum::Genesis,
+                    ChapterNumber::try_from(1u8).unwrap(),
+                    Verse::try_from(1u8).unwrap()
+                )
+                .unwrap(),
+            "In the beginning God created the heaven and the earth."
+        );
+
+        assert_eq!(
+            bible
+                .verse_text(
+                    BibleEnum::Genesis,
+                    ChapterNumber::try_from(1u8).unwrap(),
+                    Verse::try_from(32u8).unwrap()
+                )
+                .unwrap_err(),
+            BibleError::VerseNotFound
+        );
+    }
+
+    #[test]
+    fn test_chapter_text() {
+        let bible: Bible = Bible::new();
+
+        let text = bible
+            .chapter_text(BibleEnum::Genesis, ChapterNumber::try_from(1u8).unwrap())
+            .unwrap();
+
+        assert_eq!(text.len(), 31);
+
+        assert_eq!(
+            bible
+                .chapter_text(BibleEnum::Genesis, ChapterNumber::try_from(51u8).unwrap())
+                .unwrap_err(),
+            BibleError::ChapterNotFound
+        );
+    }
+
     #[test]
     fn test_range() {
         let bible = Bible::new();
 
-        let range = bible.range(BibleEnum::Exodus..BibleEnum::Numbers);
+        let range = bible.range(BibleEnum::Exodus..=BibleEnum::Numbers);
 
         let result: [&str; 3] = ["Exodus", "Leviticus", "Numbers"];
 
+        assert_eq!(range.clone().count(), result.len());
         for (i, (_id, book)) in range.enumerate() {
             assert_eq!(book.name.to_string(), result[i])
         }
+
+        assert_eq!(
+            bible.range(BibleEnum::Exodus..BibleEnum::Numbers).count(),
+            2
+        );
+        assert_eq!(bible.range(BibleEnum::Jude..).count(), 18);
+    }
+
+    #[test]
+    fn test_verses() {
+        let bible = Bible::new();
+        let verse = |book: BibleEnum, chapter: u8, verse: u8| {
+            VerseRef::new(book, chapter.try_into().unwrap(), verse.try_into().unwrap())
+        };
+        let genesis_end = verse(BibleEnum::Genesis, 50, 25);
+        let exodus_start = verse(BibleEnum::Exodus, 1, 2);
+
+        let verses: Vec<VerseRef> = bible.verses(genesis_end..=exodus_start).unwrap().collect();
+        assert_eq!(
+            verses,
+            vec![
+                genesis_end,
+                verse(BibleEnum::Genesis, 50, 26),
+                verse(BibleEnum::Exodus, 1, 1),
+                exodus_start
+            ]
+        );
+        assert_eq!(bible.verses(genesis_end..exodus_start).unwrap().len(), 3);
+        assert_eq!(
+            bible.verses(genesis_end..).unwrap().next_back(),
+            bible.verse_at(bible.verse_count()).ok()
+        );
+        assert_eq!(bible.verses(exodus_start..genesis_end).unwrap().len(), 0);
+        assert_eq!(bible.verses(..).unwrap().len() as u32, bible.verse_count());
+        assert!(bible.verses(verse(BibleEnum::Genesis, 50, 27)..).is_err());
     }
 }
@@ -0,0 +1,163 @@
+use book::book_components::name::BookName;
+use serde::{Deserialize, Serialize};
+use strum::EnumIter;
+
+use crate::{
+    bible_enum::BibleEnum,
+    constants::{ENGLISH_BOOK_NAME_LIST, FRENCH_BOOK_NAME_LIST},
+    verse_ref::VerseRef,
+};
+
+/// The languages of the book names
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    Default,
+    EnumIter,
+    Serialize,
+    Deserialize,
+)]
+pub enum Locale {
+    #[default]
+    #[serde(rename = "en")]
+    English,
+    #[serde(rename = "fr")]
+    French,
+}
+
+#[derive(Debug, thiserror::Error, PartialEq)]
+#[error("This locale is unknown: {0}")]
+pub struct LocaleError(String);
+
+impl std::str::FromStr for Locale {
+    type Err = LocaleError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "en" | "english" => Ok(Locale::English),
+            "fr" | "french" | "français" | "francais" => Ok(Locale::French),
+            _ => Err(LocaleError(s.to_string())),
+        }
+    }
+}
+
+impl Locale {
+    /// The language code of the locale
+    pub fn code(&self) -> &'static str {
+        match self {
+            Locale::English => "en",
+            Locale::French => "fr",
+        }
+    }
+
+    /// The names of the books in this locale, in the order of `BibleEnum`
+    pub fn book_names(&self) -> &'static [&'static str] {
+        match self {
+            Locale::English => ENGLISH_BOOK_NAME_LIST,
+            Locale::French => FRENCH_BOOK_NAME_LIST,
+        }
+    }
+
+    pub fn book_name(&self, book: &BibleEnum) -> BookName {
+        BookName::try_from(self.book_names()[*book as usize])
+            .expect("The book names of the constants are valid")
+    }
+}
+
+impl BibleEnum {
+    pub fn localized_name(&self, locale: &Locale) -> &'static str {
+        locale.book_names()[*self as usize]
+    }
+}
+
+/// A value that can be shown with the book names of a locale
+pub trait LocalizedDisplay {
+    fn fmt_localized(&self, locale: &Locale, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result;
+
+    fn localized(&self, locale: Locale) -> Localized<'_, Self> {
+        Localized {
+            value: self,
+            locale,
+        }
+    }
+}
+
+/// A value displayed in a locale
+pub struct Localized<'a, T: ?Sized> {
+    value: &'a T,
+    locale: Locale,
+}
+
+impl<T: LocalizedDisplay + ?Sized> std::fmt::Display for Localized<'_, T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.value.fmt_localized(&self.locale, f)
+    }
+}
+
+impl LocalizedDisplay for VerseRef {
+    fn fmt_localized(&self, locale: &Locale, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} {}:{}",
+            self.book.localized_name(locale),
+            u8::from(self.chapter),
+            u8::from(self.verse)
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use strum::IntoEnumIterator;
+
+    use super::*;
+
+    #[test]
+    fn test_every_book_is_named() {
+        for locale in Locale::iter() {
+            assert_eq!(locale.book_names().len(), BibleEnum::iter().count());
+
+            for book in BibleEnum::iter() {
+                locale.book_name(&book);
+            }
+        }
+    }
+
+    #[test]
+    fn test_localized_name() {
+        assert_eq!(BibleEnum::Isaiah.localized_name(&Locale::French), "Ésaïe");
+        assert_eq!(
+            Locale::French.book_name(&BibleEnum::Revelation).to_string(),
+            "Apocalypse"
+        );
+    }
+
+    #[test]
+    fn test_localized_verse_ref() {
+        let verse_ref = VerseRef::new(
+            BibleEnum::John,
+            3u8.try_into().unwrap(),
+            16u8.try_into().unwrap(),
+        );
+
+        assert_eq!(verse_ref.localized(Locale::French).to_string(), "Jean 3:16");
+        assert_eq!(
+            verse_ref.localized(Locale::English).to_string(),
+            "John 3:16"
+        );
+    }
+
+    #[test]
+    fn test_from_str() {
+        assert_eq!(Locale::from_str("FR").unwrap(), Locale::French);
+        assert!(Locale::from_str("de").is_err());
+    }
+}
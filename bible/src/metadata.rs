@@ -0,0 +1,304 @@
+use std::collections::BTreeMap;
+
+use book::Book;
+use serde::{Deserialize, Serialize};
+use strum::EnumIter;
+
+use crate::{
+    bible_enum::BibleEnum::{self, *},
+    canon::Canon,
+    constants::{ALTERNATIVE_NAME_LIST, OSIS_ID_LIST, USFM_ID_LIST},
+    locale::{Locale, LocalizedDisplay},
+    Bible,
+};
+
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, EnumIter, Serialize, Deserialize,
+)]
+#[serde(rename_all = "kebab-case")]
+pub enum Testament {
+    Old,
+    New,
+}
+
+/// The groups of books in the order of the Bible
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, EnumIter, Serialize, Deserialize,
+)]
+#[serde(rename_all = "kebab-case")]
+pub enum Section {
+    Law,
+    /// The historical books of both testaments, Acts included
+    History,
+    Wisdom,
+    MajorProphets,
+    MinorProphets,
+    Gospels,
+    PaulineEpistles,
+    GeneralEpistles,
+    Apocalyptic,
+}
+
+/// The literary genre of a book
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, EnumIter, Serialize, Deserialize,
+)]
+#[serde(rename_all = "kebab-case")]
+pub enum Genre {
+    Law,
+    Narrative,
+    Poetry,
+    Wisdom,
+    Prophecy,
+    Gospel,
+    Epistle,
+    Apocalyptic,
+}
+
+impl LocalizedDisplay for Testament {
+    fn fmt_localized(&self, locale: &Locale, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = match (locale, self) {
+            (Locale::English, Testament::Old) => "Old Testament",
+            (Locale::English, Testament::New) => "New Testament",
+            (Locale::French, Testament::Old) => "Ancien Testament",
+            (Locale::French, Testament::New) => "Nouveau Testament",
+        };
+
+        write!(f, "{name}")
+    }
+}
+
+impl std::fmt::Display for Testament {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.fmt_localized(&Locale::English, f)
+    }
+}
+
+impl LocalizedDisplay for Section {
+    fn fmt_localized(&self, locale: &Locale, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = match locale {
+            Locale::English => match self {
+                Section::Law => "Law",
+                Section::History => "History",
+                Section::Wisdom => "Wisdom",
+                Section::MajorProphets => "Major Prophets",
+                Section::MinorProphets => "Minor Prophets",
+                Section::Gospels => "Gospels",
+                Section::PaulineEpistles => "Pauline Epistles",
+                Section::GeneralEpistles => "General Epistles",
+                Section::Apocalyptic => "Apocalyptic",
+            },
+            Locale::French => match self {
+                Section::Law => "Loi",
+                Section::History => "Livres historiques",
+                Section::Wisdom => "Livres de sagesse",
+                Section::MajorProphets => "Grands prophètes",
+                Section::MinorProphets => "Petits prophètes",
+                Section::Gospels => "Évangiles",
+                Section::PaulineEpistles => "Épîtres de Paul",
+                Section::GeneralEpistles => "Épîtres générales",
+                Section::Apocalyptic => "Apocalypse",
+            },
+        };
+
+        write!(f, "{name}")
+    }
+}
+
+impl std::fmt::Display for Section {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.fmt_localized(&Locale::English, f)
+    }
+}
+
+/// What is known of a book apart from its text
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct BookMetadata {
+    pub book: BibleEnum,
+    pub testament: Testament,
+    pub section: Section,
+    pub genre: Genre,
+    /// Not in the Protestant canon
+    pub deuterocanonical: bool,
+    pub osis_id: &'static str,
+    pub usfm_id: &'static str,
+    /// Other names of the book, like "Qoheleth" for Ecclesiastes
+    pub alternative_names: &'static [&'static str],
+}
+
+impl BibleEnum {
+    pub fn metadata(&self) -> BookMetadata {
+        BookMetadata {
+            book: *self,
+            testament: self.testament(),
+            section: self.section(),
+            genre: self.genre(),
+            deuterocanonical: !Canon::Protestant.contains(self),
+            osis_id: OSIS_ID_LIST[*self as usize],
+            usfm_id: USFM_ID_LIST[*self as usize],
+            alternative_names: ALTERNATIVE_NAME_LIST[*self as usize],
+        }
+    }
+
+    /// The book of a USFM identifier, like "GEN" or "1CO", in any case
+    pub fn from_usfm_id(id: &str) -> Option<BibleEnum> {
+        USFM_ID_LIST
+            .iter()
+            .position(|usfm_id| usfm_id.eq_ignore_ascii_case(id))
+            .and_then(BibleEnum::from_repr)
+    }
+
+    /// The book of an OSIS identifier, like "Gen" or "1Cor"
+    pub fn from_osis_id(id: &str) -> Option<BibleEnum> {
+        OSIS_ID_LIST
+            .iter()
+            .position(|osis_id| *osis_id == id)
+            .and_then(BibleEnum::from_repr)
+    }
+
+    pub fn testament(&self) -> Testament {
+        match self.section() {
+            Section::Gospels
+            | Section::PaulineEpistles
+            | Section::GeneralEpistles
+            | Section::Apocalyptic => Testament::New,
+            Section::History if *self == Acts => Testament::New,
+            _ => Testament::Old,
+        }
+    }
+
+    pub fn section(&self) -> Section {
+        match self {
+            Genesis | Exodus | Leviticus | Numbers | Deuteronomy => Section::Law,
+            Joshua | Judges | Ruth | FirstSamuel | SecondSamuel | FirstKings | SecondKings
+            | FirstChronicles | SecondChronicles | Ezra | Nehemiah | Esther | Tobit | Judith
+            | AdditionsToEsther | FirstMaccabees | SecondMaccabees | ThirdMaccabees
+            | FourthMaccabees | FirstEsdras | Acts => Section::History,
+            Job | Psalms | Proverbs | Ecclesiastes | SongOfSolomon | WisdomOfSolomon | Sirach
+            | PrayerOfManasseh | Psalm151 => Section::Wisdom,
+            Isaiah | Jeremiah | Lamentations | Ezekiel | Daniel | Baruch | PrayerOfAzariah
+            | Susanna | BelAndTheDragon => Section::MajorProphets,
+            Hosea | Joel | Amos | Obadiah | Jonah | Micah | Nahum | Habakkuk | Zephaniah
+            | Haggai | Zechariah | Malachi => Section::MinorProphets,
+            Matthew | Mark | Luke | John => Section::Gospels,
+            Romans | FirstCorinthians | SecondCorinthians | Galatians | Ephesians | Philippians
+            | Colossians | FirstThessalonians | SecondThessalonians | FirstTimothy
+            | SecondTimothy | Titus | Philemon => Section::PaulineEpistles,
+            Hebrews | James | FirstPeter | SecondPeter | FirstJohn | SecondJohn | ThirdJohn
+            | Jude => Section::GeneralEpistles,
+            Revelation => Section::Apocalyptic,
+        }
+    }
+
+    pub fn genre(&self) -> Genre {
+        match self {
+            Psalms | SongOfSolomon | Lamentations | PrayerOfManasseh | Psalm151
+            | PrayerOfAzariah => Genre::Poetry,
+            Susanna | BelAndTheDragon => Genre::Narrative,
+            _ => match self.section() {
+                Section::Law => Genre::Law,
+                Section::History => Genre::Narrative,
+                Section::Wisdom => Genre::Wisdom,
+                Section::MajorProphets | Section::MinorProphets => Genre::Prophecy,
+                Section::Gospels => Genre::Gospel,
+                Section::PaulineEpistles | Section::GeneralEpistles => Genre::Epistle,
+                Section::Apocalyptic => Genre::Apocalyptic,
+            },
+        }
+    }
+}
+
+impl Bible {
+    /// The books of a section, in the order of the Bible
+    pub fn books_in_section(&self, section: Section) -> impl Iterator<Item = (&BibleEnum, &Book)> {
+        self.books()
+            .filter(move |(book, _)| book.section() == section)
+    }
+
+    /// The books of a testament, in the order of the Bible
+    pub fn books_in_testament(
+        &self,
+        testament: Testament,
+    ) -> impl Iterator<Item = (&BibleEnum, &Book)> {
+        self.books()
+            .filter(move |(book, _)| book.testament() == testament)
+    }
+
+    /// The books of the Bible grouped by section
+    pub fn books_by_section(&self) -> BTreeMap<Section, Vec<BibleEnum>> {
+        let mut sections: BTreeMap<Section, Vec<BibleEnum>> = BTreeMap::new();
+
+        for (book, _) in self.books.iter() {
+            sections.entry(book.section()).or_default().push(*book);
+        }
+
+        sections
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use strum::IntoEnumIterator;
+
+    use super::*;
+    use crate::BIBLE;
+
+    #[test]
+    fn test_metadata() {
+        let metadata = Ecclesiastes.metadata();
+
+        assert_eq!(metadata.testament, Testament::Old);
+        assert_eq!(metadata.section, Section::Wisdom);
+        assert_eq!(metadata.osis_id, "Eccl");
+        assert_eq!(metadata.usfm_id, "ECC");
+        assert_eq!(metadata.alternative_names, &["Qoheleth"]);
+        assert!(!metadata.deuterocanonical);
+        assert!(Sirach.metadata().deuterocanonical);
+    }
+
+    #[test]
+    fn test_identifiers() {
+        assert_eq!(BibleEnum::from_usfm_id("1CO"), Some(FirstCorinthians));
+        assert_eq!(BibleEnum::from_usfm_id("jhn"), Some(John));
+        assert_eq!(BibleEnum::from_usfm_id("XXA"), None);
+        assert_eq!(BibleEnum::from_osis_id("1Cor"), Some(FirstCorinthians));
+        assert_eq!(BibleEnum::from_osis_id("Jude"), Some(Jude));
+        assert_eq!(BibleEnum::from_osis_id("JUDE"), None);
+    }
+
+    #[test]
+    fn test_testaments() {
+        let new_testament: Vec<BibleEnum> = BibleEnum::iter()
+            .filter(|book| book.testament() == Testament::New)
+            .collect();
+
+        assert_eq!(new_testament.len(), 27);
+        assert_eq!(new_testament.first(), Some(&Matthew));
+        assert_eq!(Acts.genre(), Genre::Narrative);
+    }
+
+    #[test]
+    fn test_localized_labels() {
+        assert_eq!(Testament::Old.to_string(), "Old Testament");
+        assert_eq!(
+            Testament::New.localized(Locale::French).to_string(),
+            "Nouveau Testament"
+        );
+        assert_eq!(Section::PaulineEpistles.to_string(), "Pauline Epistles");
+        assert_eq!(
+            Section::Gospels.localized(Locale::French).to_string(),
+            "Évangiles"
+        );
+    }
+
+    #[test]
+    fn test_sections() {
+        let sections = BIBLE.books_by_section();
+
+        assert_eq!(sections[&Section::Law].len(), 5);
+        assert_eq!(sections[&Section::MinorProphets].len(), 12);
+        assert_eq!(sections[&Section::Gospels], vec![Matthew, Mark, Luke, John]);
+        assert_eq!(BIBLE.books_in_section(Section::PaulineEpistles).count(), 13);
+        assert_eq!(BIBLE.books_in_testament(Testament::Old).count(), 39 + 16);
+    }
+}
@@ -0,0 +1,289 @@
+use std::iter::FusedIterator;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    locale::{Locale, LocalizedDisplay},
+    reference::ScriptureReference,
+    verse_ref::VerseRef,
+    Bible, BIBLE,
+};
+
+/// A contiguous span of verses, which may cross chapters and books
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct Passage {
+    start: VerseRef,
+    end: VerseRef,
+}
+
+#[derive(Debug, thiserror::Error, PartialEq)]
+pub enum PassageError {
+    #[error("{0} is not in the Bible")]
+    VerseNotFound(VerseRef),
+    #[error("The passage ends at {end} before it starts at {start}")]
+    Reversed { start: VerseRef, end: VerseRef },
+}
+
+impl Passage {
+    /// Create a passage of `BIBLE`, both verses included
+    pub fn new(start: VerseRef, end: VerseRef) -> Result<Passage, PassageError> {
+        Passage::new_in(start, end, &BIBLE)
+    }
+
+    /// Create a passage of a Bible, both verses included
+    pub fn new_in(start: VerseRef, end: VerseRef, bible: &Bible) -> Result<Passage, PassageError> {
+        for verse_ref in [start, end] {
+            bible
+                .check_verse(&verse_ref)
+                .map_err(|_| PassageError::VerseNotFound(verse_ref))?;
+        }
+
+        if start > end {
+            return Err(PassageError::Reversed { start, end });
+        }
+
+        Ok(Passage { start, end })
+    }
+
+    /// The passage of a single verse
+    pub fn verse(verse_ref: VerseRef) -> Result<Passage, PassageError> {
+        Passage::new(verse_ref, verse_ref)
+    }
+
+    pub fn from_reference(reference: &ScriptureReference) -> Result<Passage, PassageError> {
+        Passage::from_reference_in(reference, &BIBLE)
+    }
+
+    pub fn from_reference_in(
+        reference: &ScriptureReference,
+        bible: &Bible,
+    ) -> Result<Passage, PassageError> {
+        let start = reference.first_verse();
+        let end = reference
+            .last_verse_in(bible)
+            .map_err(|_| PassageError::VerseNotFound(start))?;
+
+        Passage::new_in(start, end, bible)
+    }
+
+    pub fn start(&self) -> &VerseRef {
+        &self.start
+    }
+
+    pub fn end(&self) -> &VerseRef {
+        &self.end
+    }
+
+    /// The number of verses of the passage in `BIBLE`
+    pub fn verse_count(&self) -> usize {
+        self.verse_count_in(&BIBLE)
+    }
+
+    pub fn verse_count_in(&self, bible: &Bible) -> usize {
+        bible
+            .verses_between(&self.start, &self.end)
+            .map_or(0, |distance| distance as usize + 1)
+    }
+
+    pub fn contains(&self, verse_ref: &VerseRef) -> bool {
+        self.start <= *verse_ref && *verse_ref <= self.end
+    }
+
+    /// Whether the passages share at least one verse
+    pub fn overlaps(&self, other: &Passage) -> bool {
+        self.start <= other.end && other.start <= self.end
+    }
+
+    /// The passage covering both passages of `BIBLE`, if they overlap or
+    /// follow each other
+    pub fn merge(&self, other: &Passage) -> Option<Passage> {
+        self.merge_in(other, &BIBLE)
+    }
+
+    pub fn merge_in(&self, other: &Passage, bible: &Bible) -> Option<Passage> {
+        let adjacent = bible.next_verse(&self.end) == Some(other.start)
+            || bible.next_verse(&other.end) == Some(self.start);
+
+        if !self.overlaps(other) && !adjacent {
+            return None;
+        }
+
+        Some(Passage {
+            start: self.start.min(other.start),
+            end: self.end.max(other.end),
+        })
+    }
+
+    /// Every verse of the passage in `BIBLE`
+    pub fn verses(&self) -> PassageVerses<'static> {
+        self.verses_in(&BIBLE)
+    }
+
+    pub fn verses_in<'a>(&self, bible: &'a Bible) -> PassageVerses<'a> {
+        PassageVerses {
+            bible,
+            front: Some(self.start),
+            back: self.end,
+        }
+    }
+}
+
+impl LocalizedDisplay for Passage {
+    fn fmt_localized(&self, locale: &Locale, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.start == self.end {
+            self.start.fmt_localized(locale, f)
+        } else if self.start.book == self.end.book {
+            ScriptureReference::VerseRange {
+                first: self.start,
+                last: self.end,
+            }
+            .fmt_localized(locale, f)
+        } else {
+            write!(
+                f,
+                "{}–{}",
+                self.start.localized(*locale),
+                self.end.localized(*locale)
+            )
+        }
+    }
+}
+
+impl std::fmt::Display for Passage {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.fmt_localized(&Locale::English, f)
+    }
+}
+
+/// The verses of a passage, from both ends
+#[derive(Debug, Clone)]
+pub struct PassageVerses<'a> {
+    bible: &'a Bible,
+    /// The next verse from the start, `None` once every verse is given
+    front: Option<VerseRef>,
+    back: VerseRef,
+}
+
+impl Iterator for PassageVerses<'_> {
+    type Item = VerseRef;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let current = self.front?;
+
+        self.front = if current == self.back {
+            None
+        } else {
+            self.bible.next_verse(&current)
+        };
+
+        Some(current)
+    }
+}
+
+impl DoubleEndedIterator for PassageVerses<'_> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let front = self.front?;
+        let current = self.back;
+
+        if current == front {
+            self.front = None;
+        } else {
+            match self.bible.previous_verse(&current) {
+                Some(previous) => self.back = previous,
+                None => self.front = None,
+            }
+        }
+
+        Some(current)
+    }
+}
+
+impl FusedIterator for PassageVerses<'_> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bible_enum::BibleEnum;
+
+    fn verse(book: BibleEnum, chapter: u8, verse: u8) -> VerseRef {
+        VerseRef::new(book, chapter.try_into().unwrap(), verse.try_into().unwrap())
+    }
+
+    #[test]
+    fn test_validation() {
+        assert_eq!(
+            Passage::new(verse(BibleEnum::John, 3, 18), verse(BibleEnum::John, 3, 16)),
+            Err(PassageError::Reversed {
+                start: verse(BibleEnum::John, 3, 18),
+                end: verse(BibleEnum::John, 3, 16),
+            })
+        );
+        assert_eq!(
+            Passage::verse(verse(BibleEnum::John, 3, 37)),
+            Err(PassageError::VerseNotFound(verse(BibleEnum::John, 3, 37)))
+        );
+    }
+
+    #[test]
+    fn test_verses_across_books() {
+        let passage = Passage::new(
+            verse(BibleEnum::Genesis, 50, 25),
+            verse(BibleEnum::Exodus, 1, 2),
+        )
+        .unwrap();
+
+        let verses: Vec<VerseRef> = passage.verses().collect();
+        assert_eq!(
+            verses,
+            vec![
+                verse(BibleEnum::Genesis, 50, 25),
+                verse(BibleEnum::Genesis, 50, 26),
+                verse(BibleEnum::Exodus, 1, 1),
+                verse(BibleEnum::Exodus, 1, 2),
+            ]
+        );
+
+        let reversed: Vec<VerseRef> = passage.verses().rev().collect();
+        assert_eq!(reversed, verses.into_iter().rev().collect::<Vec<_>>());
+        assert_eq!(passage.to_string(), "Genesis 50:25–Exodus 1:2");
+        assert_eq!(
+            passage.localized(Locale::French).to_string(),
+            "Genèse 50:25–Exode 1:2"
+        );
+    }
+
+    #[test]
+    fn test_both_ends_meet() {
+        let passage = Passage::from_reference(&"Jude 1-3".parse().unwrap()).unwrap();
+        let mut verses = passage.verses();
+
+        assert_eq!(verses.next(), Some(verse(BibleEnum::Jude, 1, 1)));
+        assert_eq!(verses.next_back(), Some(verse(BibleEnum::Jude, 1, 3)));
+        assert_eq!(verses.next_back(), Some(verse(BibleEnum::Jude, 1, 2)));
+        assert_eq!(verses.next(), None);
+        assert_eq!(verses.next_back(), None);
+    }
+
+    #[test]
+    fn test_verse_count() {
+        let psalms = Passage::from_reference(&"Ps 117-118".parse().unwrap()).unwrap();
+
+        assert_eq!(psalms.verse_count(), 2 + 29);
+        assert!(psalms.contains(&verse(BibleEnum::Psalms, 118, 8)));
+        assert!(!psalms.contains(&verse(BibleEnum::Psalms, 119, 1)));
+    }
+
+    #[test]
+    fn test_overlap_and_merge() {
+        let first = Passage::from_reference(&"John 3:1-16".parse().unwrap()).unwrap();
+        let second = Passage::from_reference(&"John 3:10-21".parse().unwrap()).unwrap();
+        let next = Passage::from_reference(&"John 3:22-36".parse().unwrap()).unwrap();
+        let far = Passage::from_reference(&"John 5".parse().unwrap()).unwrap();
+
+        assert!(first.overlaps(&second));
+        assert!(!first.overlaps(&next));
+        assert_eq!(first.merge(&second).unwrap().to_string(), "John 3:1–21");
+        assert_eq!(second.merge(&next).unwrap().to_string(), "John 3:10–36");
+        assert_eq!(first.merge(&far), None);
+    }
+}
@@ -0,0 +1,540 @@
+use book::book_components::{chapter_number::ChapterNumber, verse::Verse};
+
+use crate::{
+    alias::find_alias,
+    bible_enum::BibleEnum,
+    citation::{CitationFormatter, CitationStyle},
+    locale::{Locale, LocalizedDisplay},
+    verse_ref::VerseRef,
+    Bible, BibleError, BIBLE,
+};
+
+/// A reference to a passage of a book, as written in "John 3:16-18" or "Ps 119"
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ScriptureReference {
+    /// A whole chapter, like "Psalms 119"
+    Chapter {
+        book: BibleEnum,
+        chapter: ChapterNumber,
+    },
+    /// Whole chapters, like "Genesis 1-3"
+    ChapterRange {
+        book: BibleEnum,
+        first: ChapterNumber,
+        last: ChapterNumber,
+    },
+    /// A single verse, like "John 3:16"
+    Verse(VerseRef),
+    /// Verses of a book, maybe over several chapters, like "John 3:16-4:2"
+    VerseRange { first: VerseRef, last: VerseRef },
+}
+
+#[derive(Debug, thiserror::Error, PartialEq)]
+pub enum ReferenceError {
+    #[error("The reference is empty")]
+    Empty,
+    #[error("This book is unknown: {0}")]
+    UnknownBook(String),
+    #[error("No book is given for {0}")]
+    MissingBook(String),
+    #[error("No chapter is given for {0}")]
+    MissingChapter(String),
+    #[error("This reference cannot be read: {0}")]
+    Invalid(String),
+    #[error("{book:?} has no chapter {chapter}")]
+    ChapterNotFound { book: BibleEnum, chapter: u8 },
+    #[error("{book:?} {chapter} has no verse {verse}")]
+    VerseNotFound {
+        book: BibleEnum,
+        chapter: u8,
+        verse: u8,
+    },
+    #[error("The range {0} ends before it starts")]
+    ReversedRange(String),
+    #[error("A single reference is expected but {0} are given")]
+    NotSingle(usize),
+}
+
+impl std::str::FromStr for ScriptureReference {
+    type Err = ReferenceError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let references = ScriptureReference::parse_list(s)?;
+
+        match references.as_slice() {
+            [reference] => Ok(*reference),
+            _ => Err(ReferenceError::NotSingle(references.len())),
+        }
+    }
+}
+
+impl ScriptureReference {
+    /// Read a list of references, like "John 3:16-18; Rom 8:28, 31", checked
+    /// against `BIBLE`
+    pub fn parse_list(input: &str) -> Result<Vec<ScriptureReference>, ReferenceError> {
+        ScriptureReference::parse_list_in(input, &BIBLE)
+    }
+
+    /// Read a list of references checked against a Bible.
+    ///
+    /// The references are separated by semicolons, and a reference without a
+    /// book takes the one of the previous reference. Inside a reference, the
+    /// commas separate the chapters, or the verses when a verse is given before.
+    /// In a book of a single chapter, a lone number is a verse, and the book may
+    /// be given alone.
+    pub fn parse_list_in(
+        input: &str,
+        bible: &Bible,
+    ) -> Result<Vec<ScriptureReference>, ReferenceError> {
+        let mut references = Vec::new();
+        let mut book: Option<BibleEnum> = None;
+
+        for group in input
+            .split(';')
+            .map(str::trim)
+            .filter(|group| !group.is_empty())
+        {
+            let (book_part, location) = split_book(group);
+
+            if !book_part.is_empty() {
+                book = Some(
+                    book_part
+                        .parse()
+                        .map_err(|_| ReferenceError::UnknownBook(book_part.to_string()))?,
+                );
+            }
+
+            let book = book.ok_or_else(|| ReferenceError::MissingBook(group.to_string()))?;
+
+            let single_chapter = bible
+                .get(book)
+                .is_some_and(|book| (&book.chapters).into_iter().count() == 1);
+
+            if location.is_empty() {
+                // A book of a single chapter may be given alone, like "Ps 151"
+                if !single_chapter {
+                    return Err(ReferenceError::MissingChapter(group.to_string()));
+                }
+
+                references.push(ScriptureReference::Chapter {
+                    book,
+                    chapter: ChapterNumber::try_from(1u8).expect("1 is a valid chapter"),
+                });
+                continue;
+            }
+
+            let mut verse_chapter: Option<u8> = None;
+
+            for item in location.split(',').map(str::trim) {
+                let reference = parse_item(book, item, verse_chapter, single_chapter)?;
+                reference.validate_in(bible, item)?;
+
+                verse_chapter = match reference {
+                    ScriptureReference::Verse(verse_ref) => Some(verse_ref.chapter.into()),
+                    ScriptureReference::VerseRange { last, .. } => Some(last.chapter.into()),
+                    _ => None,
+                };
+
+                references.push(reference);
+            }
+        }
+
+        if references.is_empty() {
+            return Err(ReferenceError::Empty);
+        }
+
+        Ok(references)
+    }
+
+    pub fn book(&self) -> BibleEnum {
+        match self {
+            ScriptureReference::Chapter { book, .. }
+            | ScriptureReference::ChapterRange { book, .. } => *book,
+            ScriptureReference::Verse(verse_ref) => verse_ref.book,
+            ScriptureReference::VerseRange { first, .. } => first.book,
+        }
+    }
+
+    /// The first verse of the passage
+    pub fn first_verse(&self) -> VerseRef {
+        match self {
+            ScriptureReference::Chapter { book, chapter } => {
+                VerseRef::new(*book, *chapter, first_verse_number())
+            }
+            ScriptureReference::ChapterRange { book, first, .. } => {
+                VerseRef::new(*book, *first, first_verse_number())
+            }
+            ScriptureReference::Verse(verse_ref) => *verse_ref,
+            ScriptureReference::VerseRange { first, .. } => *first,
+        }
+    }
+
+    /// The last verse of the passage in `BIBLE`
+    pub fn last_verse(&self) -> Result<VerseRef, BibleError> {
+        self.last_verse_in(&BIBLE)
+    }
+
+    /// The last verse of the passage in a Bible
+    pub fn last_verse_in(&self, bible: &Bible) -> Result<VerseRef, BibleError> {
+        match self {
+            ScriptureReference::Chapter { book, chapter }
+            | ScriptureReference::ChapterRange {
+                book,
+                last: chapter,
+                ..
+            } => Ok(VerseRef::new(
+                *book,
+                *chapter,
+                *bible.try_get_chapter(*book, *chapter)?.get_max_verse(),
+            )),
+            ScriptureReference::Verse(verse_ref) => Ok(*verse_ref),
+            ScriptureReference::VerseRange { last, .. } => Ok(*last),
+        }
+    }
+
+    fn validate_in(&self, bible: &Bible, item: &str) -> Result<(), ReferenceError> {
+        let reversed = match self {
+            ScriptureReference::Chapter { book, chapter } => {
+                check_chapter(bible, *book, *chapter)?;
+                false
+            }
+            ScriptureReference::ChapterRange { book, first, last } => {
+                check_chapter(bible, *book, *first)?;
+                check_chapter(bible, *book, *last)?;
+                first > last
+            }
+            ScriptureReference::Verse(verse_ref) => {
+                check_verse(bible, verse_ref)?;
+                false
+            }
+            ScriptureReference::VerseRange { first, last } => {
+                check_verse(bible, first)?;
+                check_verse(bible, last)?;
+                first > last
+            }
+        };
+
+        if reversed {
+            return Err(ReferenceError::ReversedRange(item.to_string()));
+        }
+
+        Ok(())
+    }
+}
+
+impl LocalizedDisplay for ScriptureReference {
+    fn fmt_localized(&self, locale: &Locale, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let formatter = CitationFormatter::new(CitationStyle::Long).with_locale(*locale);
+
+        write!(f, "{}", formatter.format_reference(self))
+    }
+}
+
+impl std::fmt::Display for ScriptureReference {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.fmt_localized(&Locale::English, f)
+    }
+}
+
+/// Split a reference between its book, maybe empty, and its chapters and verses.
+///
+/// The location is read from the trailing numbers and punctuation, but the
+/// longest start of the reference which is a known name of a book is taken
+/// first, so that "Psalm 151 1" is the first verse of Psalm 151.
+fn split_book(group: &str) -> (&str, &str) {
+    let location_start = group
+        .char_indices()
+        .rev()
+        .take_while(|(_, c)| {
+            c.is_ascii_digit()
+                || c.is_whitespace()
+                || matches!(c, ':' | '.' | ',' | '-' | '–' | '—')
+        })
+        .last()
+        .map_or(group.len(), |(index, _)| index);
+
+    let number_starts = group[location_start..]
+        .char_indices()
+        .filter(|(offset, c)| {
+            c.is_ascii_digit()
+                && !group[..location_start + offset]
+                    .ends_with(|previous: char| previous.is_ascii_digit())
+        })
+        .map(|(offset, _)| location_start + offset);
+
+    let split = std::iter::once(group.len())
+        .chain(number_starts.collect::<Vec<_>>().into_iter().rev())
+        .find(|index| find_alias(group[..*index].trim()).is_some())
+        .or_else(|| {
+            group[location_start..]
+                .find(|c: char| c.is_ascii_digit())
+                .map(|offset| location_start + offset)
+        })
+        .unwrap_or(group.len());
+
+    (group[..split].trim(), group[split..].trim())
+}
+
+/// Read a chapter, a verse or a range, like "3", "3:16", "16-18" or "3:16-4:2".
+///
+/// `verse_chapter` is the chapter of the previous verse of the list, in which
+/// a lone number is a verse.
+fn parse_item(
+    book: BibleEnum,
+    item: &str,
+    verse_chapter: Option<u8>,
+    single_chapter: bool,
+) -> Result<ScriptureReference, ReferenceError> {
+    let invalid = || ReferenceError::Invalid(item.to_string());
+
+    let mut bounds = item.split(['-', '–', '—']);
+    let start = parse_point(bounds.next().ok_or_else(invalid)?).ok_or_else(invalid)?;
+    let end = match bounds.next() {
+        Some(end) => Some(parse_point(end).ok_or_else(invalid)?),
+        None => None,
+    };
+
+    if bounds.next().is_some() {
+        return Err(invalid());
+    }
+
+    let start = match (start, verse_chapter) {
+        ((number, None), Some(chapter)) => (chapter, Some(number)),
+        ((number, None), None) if single_chapter => (1, Some(number)),
+        (start, _) => start,
+    };
+
+    let verse_ref = |chapter: u8, verse: u8| -> Result<VerseRef, ReferenceError> {
+        Ok(VerseRef::new(
+            book,
+            ChapterNumber::try_from(chapter)
+                .map_err(|_| ReferenceError::ChapterNotFound { book, chapter })?,
+            Verse::try_from(verse).map_err(|_| ReferenceError::VerseNotFound {
+                book,
+                chapter,
+                verse,
+            })?,
+        ))
+    };
+    let chapter_number = |chapter: u8| {
+        ChapterNumber::try_from(chapter)
+            .map_err(|_| ReferenceError::ChapterNotFound { book, chapter })
+    };
+
+    Ok(match (start, end) {
+        ((chapter, None), None) => ScriptureReference::Chapter {
+            book,
+            chapter: chapter_number(chapter)?,
+        },
+        ((chapter, Some(verse)), None) => ScriptureReference::Verse(verse_ref(chapter, verse)?),
+        ((first, None), Some((last, None))) => ScriptureReference::ChapterRange {
+            book,
+            first: chapter_number(first)?,
+            last: chapter_number(last)?,
+        },
+        ((first, None), Some((last, Some(last_verse)))) => ScriptureReference::VerseRange {
+            first: verse_ref(first, 1)?,
+            last: verse_ref(last, last_verse)?,
+        },
+        ((chapter, Some(first_verse)), Some((last_verse, None))) => {
+            ScriptureReference::VerseRange {
+                first: verse_ref(chapter, first_verse)?,
+                last: verse_ref(chapter, last_verse)?,
+            }
+        }
+        ((chapter, Some(first_verse)), Some((last, Some(last_verse)))) => {
+            ScriptureReference::VerseRange {
+                first: verse_ref(chapter, first_verse)?,
+                last: verse_ref(last, last_verse)?,
+            }
+        }
+    })
+}
+
+/// Read "3" or "3:16", the verse being separated by a colon or a period
+fn parse_point(point: &str) -> Option<(u8, Option<u8>)> {
+    let mut numbers = point.trim().split([':', '.']).map(str::trim);
+
+    let first = numbers.next()?.parse().ok()?;
+    let second = match numbers.next() {
+        Some(number) => Some(number.parse().ok()?),
+        None => None,
+    };
+
+    if numbers.next().is_some() {
+        return None;
+    }
+
+    Some((first, second))
+}
+
+fn check_chapter(
+    bible: &Bible,
+    book: BibleEnum,
+    chapter: ChapterNumber,
+) -> Result<(), ReferenceError> {
+    bible
+        .try_get_chapter(book, chapter)
+        .map(|_| ())
+        .map_err(|_| ReferenceError::ChapterNotFound {
+            book,
+            chapter: chapter.into(),
+        })
+}
+
+fn check_verse(bible: &Bible, verse_ref: &VerseRef) -> Result<(), ReferenceError> {
+    let chapter = bible
+        .try_get_chapter(verse_ref.book, verse_ref.chapter)
+        .map_err(|_| ReferenceError::ChapterNotFound {
+            book: verse_ref.book,
+            chapter: verse_ref.chapter.into(),
+        })?;
+
+    if verse_ref.verse > *chapter.get_max_verse() {
+        return Err(ReferenceError::VerseNotFound {
+            book: verse_ref.book,
+            chapter: verse_ref.chapter.into(),
+            verse: verse_ref.verse.into(),
+        });
+    }
+
+    Ok(())
+}
+
+fn first_verse_number() -> Verse {
+    Verse::try_from(1u8).expect("1 is a valid verse")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn verse(book: BibleEnum, chapter: u8, verse: u8) -> VerseRef {
+        VerseRef::new(book, chapter.try_into().unwrap(), verse.try_into().unwrap())
+    }
+
+    #[test]
+    fn test_parse_list() {
+        let references = ScriptureReference::parse_list("John 3:16-18; Rom 8:28, 31").unwrap();
+
+        assert_eq!(
+            references,
+            vec![
+                ScriptureReference::VerseRange {
+                    first: verse(BibleEnum::John, 3, 16),
+                    last: verse(BibleEnum::John, 3, 18),
+                },
+                ScriptureReference::Verse(verse(BibleEnum::Romans, 8, 28)),
+                ScriptureReference::Verse(verse(BibleEnum::Romans, 8, 31)),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_chapters() {
+        assert_eq!(
+            "Ps 119".parse::<ScriptureReference>().unwrap(),
+            ScriptureReference::Chapter {
+                book: BibleEnum::Psalms,
+                chapter: 119u8.try_into().unwrap(),
+            }
+        );
+        assert_eq!(
+            ScriptureReference::parse_list("1 Sam 1-3, 5")
+                .unwrap()
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<String>>(),
+            vec!["1 Samuel 1–3", "1 Samuel 5"]
+        );
+    }
+
+    #[test]
+    fn test_cross_chapter_range() {
+        let reference: ScriptureReference = "Jn 3:16–4:2".parse().unwrap();
+
+        assert_eq!(reference.first_verse(), verse(BibleEnum::John, 3, 16));
+        assert_eq!(
+            reference.last_verse().unwrap(),
+            verse(BibleEnum::John, 4, 2)
+        );
+        assert_eq!(reference.to_string(), "John 3:16–4:2");
+    }
+
+    #[test]
+    fn test_single_chapter_book() {
+        assert_eq!(
+            "Jude 5".parse::<ScriptureReference>().unwrap(),
+            ScriptureReference::Verse(verse(BibleEnum::Jude, 1, 5))
+        );
+    }
+
+    #[test]
+    fn test_book_ending_with_a_number() {
+        assert_eq!(
+            "Psalm 151 1".parse::<ScriptureReference>().unwrap(),
+            ScriptureReference::Verse(verse(BibleEnum::Psalm151, 1, 1))
+        );
+        assert_eq!(
+            "Ps 151".parse::<ScriptureReference>().unwrap(),
+            ScriptureReference::Chapter {
+                book: BibleEnum::Psalm151,
+                chapter: 1u8.try_into().unwrap(),
+            }
+        );
+        assert_eq!(
+            "Ps 15:1".parse::<ScriptureReference>().unwrap(),
+            ScriptureReference::Verse(verse(BibleEnum::Psalms, 15, 1))
+        );
+    }
+
+    #[test]
+    fn test_book_carried_over() {
+        let references = ScriptureReference::parse_list("Gen 1:1; 2:4").unwrap();
+
+        assert_eq!(references[1].first_verse(), verse(BibleEnum::Genesis, 2, 4));
+    }
+
+    #[test]
+    fn test_errors() {
+        assert_eq!(
+            "Hezekiah 1".parse::<ScriptureReference>(),
+            Err(ReferenceError::UnknownBook("Hezekiah".to_string()))
+        );
+        assert_eq!(
+            "3:16".parse::<ScriptureReference>(),
+            Err(ReferenceError::MissingBook("3:16".to_string()))
+        );
+        assert_eq!(
+            "John".parse::<ScriptureReference>(),
+            Err(ReferenceError::MissingChapter("John".to_string()))
+        );
+        assert_eq!(
+            "John 22".parse::<ScriptureReference>(),
+            Err(ReferenceError::ChapterNotFound {
+                book: BibleEnum::John,
+                chapter: 22
+            })
+        );
+        assert_eq!(
+            "John 3:37".parse::<ScriptureReference>(),
+            Err(ReferenceError::VerseNotFound {
+                book: BibleEnum::John,
+                chapter: 3,
+                verse: 37
+            })
+        );
+        assert_eq!(
+            "John 3:18-16".parse::<ScriptureReference>(),
+            Err(ReferenceError::ReversedRange("3:18-16".to_string()))
+        );
+        assert_eq!(
+            "John 3:16; 3:17".parse::<ScriptureReference>(),
+            Err(ReferenceError::NotSingle(2))
+        );
+        assert_eq!(
+            ScriptureReference::parse_list(" ; "),
+            Err(ReferenceError::Empty)
+        );
+    }
+}
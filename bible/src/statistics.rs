@@ -0,0 +1,207 @@
+use std::collections::BTreeMap;
+
+use book::{book_components::chapter_number::ChapterNumber, Book};
+
+use crate::{
+    bible_enum::BibleEnum,
+    metadata::{Section, Testament},
+    passage::Passage,
+    Bible, BibleError,
+};
+
+/// A chapter and its number of verses
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ChapterLength {
+    pub book: BibleEnum,
+    pub chapter: ChapterNumber,
+    pub verses: u8,
+}
+
+/// The size of a set of books or of a passage
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Statistics {
+    pub books: usize,
+    pub chapters: usize,
+    pub verses: u32,
+    /// The chapter with the most verses, the first one if several have as many
+    pub longest_chapter: Option<ChapterLength>,
+    /// The chapter with the fewest verses, the first one if several have as few
+    pub shortest_chapter: Option<ChapterLength>,
+}
+
+impl Statistics {
+    pub fn from_books<'a>(
+        books: impl IntoIterator<Item = (&'a BibleEnum, &'a Book)>,
+    ) -> Statistics {
+        let mut statistics = Statistics::default();
+
+        for (book, content) in books {
+            statistics.books += 1;
+
+            for chapter in &content.chapters {
+                statistics.add_chapter(ChapterLength {
+                    book: *book,
+                    chapter: *chapter.get_chapter_number(),
+                    verses: chapter.get_max_verse().into(),
+                });
+            }
+        }
+
+        statistics
+    }
+
+    /// Count a chapter, or the part of a chapter in a passage
+    fn add_chapter(&mut self, length: ChapterLength) {
+        self.chapters += 1;
+        self.verses += length.verses as u32;
+
+        if self
+            .longest_chapter
+            .is_none_or(|longest| length.verses > longest.verses)
+        {
+            self.longest_chapter = Some(length);
+        }
+
+        if self
+            .shortest_chapter
+            .is_none_or(|shortest| length.verses < shortest.verses)
+        {
+            self.shortest_chapter = Some(length);
+        }
+    }
+
+    /// The average number of verses of a chapter
+    pub fn average_chapter_length(&self) -> f64 {
+        if self.chapters == 0 {
+            return 0.0;
+        }
+
+        self.verses as f64 / self.chapters as f64
+    }
+}
+
+impl Bible {
+    pub fn chapter_count(&self) -> usize {
+        self.into_iter().map(Book::chapter_count).sum()
+    }
+
+    pub fn statistics(&self) -> Statistics {
+        Statistics::from_books(self.books())
+    }
+
+    pub fn book_statistics(&self, book: BibleEnum) -> Result<Statistics, BibleError> {
+        let content = self.try_get(book)?;
+
+        Ok(Statistics::from_books([(&book, content)]))
+    }
+
+    pub fn testament_statistics(&self, testament: Testament) -> Statistics {
+        Statistics::from_books(self.books_in_testament(testament))
+    }
+
+    /// The statistics of every section having a book in the Bible
+    pub fn section_statistics(&self) -> BTreeMap<Section, Statistics> {
+        self.books_by_section()
+            .into_keys()
+            .map(|section| {
+                (
+                    section,
+                    Statistics::from_books(self.books_in_section(section)),
+                )
+            })
+            .collect()
+    }
+
+    /// The statistics of a passage, whose chapters are counted even when the
+    /// passage has only some of their verses
+    pub fn passage_statistics(&self, passage: &Passage) -> Statistics {
+        let mut statistics = Statistics::default();
+        let mut current: Option<ChapterLength> = None;
+
+        for verse_ref in passage.verses_in(self) {
+            match current.as_mut() {
+                Some(length)
+                    if length.book == verse_ref.book && length.chapter == verse_ref.chapter =>
+                {
+                    length.verses += 1;
+                }
+                _ => {
+                    if let Some(length) = current {
+                        statistics.add_chapter(length);
+                    }
+
+                    if current.is_none_or(|length| length.book != verse_ref.book) {
+                        statistics.books += 1;
+                    }
+
+                    current = Some(ChapterLength {
+                        book: verse_ref.book,
+                        chapter: verse_ref.chapter,
+                        verses: 1,
+                    });
+                }
+            }
+        }
+
+        if let Some(length) = current {
+            statistics.add_chapter(length);
+        }
+
+        statistics
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::BIBLE;
+
+    #[test]
+    fn test_testaments() {
+        let old = BIBLE.testament_statistics(Testament::Old);
+        let new = BIBLE.testament_statistics(Testament::New);
+
+        assert_eq!(new.books, 27);
+        assert_eq!(new.chapters, 260);
+        assert_eq!(new.verses, 7957);
+        assert_eq!(old.books + new.books, BIBLE.statistics().books);
+        assert_eq!(BIBLE.statistics().verses, BIBLE.verse_count());
+    }
+
+    #[test]
+    fn test_longest_and_shortest() {
+        let psalms = BIBLE.book_statistics(BibleEnum::Psalms).unwrap();
+
+        assert_eq!(psalms.chapters, 150);
+        assert_eq!(u8::from(psalms.longest_chapter.unwrap().chapter), 119);
+        assert_eq!(psalms.longest_chapter.unwrap().verses, 176);
+        assert_eq!(u8::from(psalms.shortest_chapter.unwrap().chapter), 117);
+    }
+
+    #[test]
+    fn test_sections() {
+        let sections = BIBLE.section_statistics();
+
+        assert_eq!(sections[&Section::Gospels].books, 4);
+        assert_eq!(sections[&Section::Gospels].chapters, 89);
+        assert_eq!(
+            sections.values().map(|section| section.verses).sum::<u32>(),
+            BIBLE.verse_count()
+        );
+    }
+
+    #[test]
+    fn test_passage() {
+        let passage = Passage::from_reference(&"Ruth 4:20-22".parse().unwrap())
+            .unwrap()
+            .merge(&Passage::from_reference(&"1 Sam 1-2".parse().unwrap()).unwrap())
+            .unwrap();
+
+        let statistics = BIBLE.passage_statistics(&passage);
+
+        assert_eq!(statistics.books, 2);
+        assert_eq!(statistics.chapters, 3);
+        assert_eq!(statistics.verses, 3 + 28 + 36);
+        assert_eq!(statistics.shortest_chapter.unwrap().verses, 3);
+    }
+}
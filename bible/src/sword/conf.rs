@@ -0,0 +1,362 @@
+//! The `.conf` files of `mods.d`, which describe the modules of a SWORD
+//! library.
+
+use std::path::PathBuf;
+
+use super::SwordError;
+use crate::{
+    bible_enum::BibleEnum::{self, *},
+    canon::Canon,
+    versification::Versification,
+};
+
+/// The storage drivers of Bible text modules
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Driver {
+    /// Uncompressed verses, indexed with 16 bit sizes
+    RawText,
+    /// Uncompressed verses, indexed with 32 bit sizes
+    RawText4,
+    /// Compressed blocks of verses, indexed with 16 bit sizes
+    ZText,
+    /// Compressed blocks of verses, indexed with 32 bit sizes
+    ZText4,
+}
+
+impl Driver {
+    /// The size in bytes of the verse sizes in the index
+    pub(crate) fn size_width(&self) -> usize {
+        match self {
+            Driver::RawText | Driver::ZText => 2,
+            Driver::RawText4 | Driver::ZText4 => 4,
+        }
+    }
+
+    pub(crate) fn is_compressed(&self) -> bool {
+        matches!(self, Driver::ZText | Driver::ZText4)
+    }
+}
+
+/// The markup of the verses of a module
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SourceType {
+    Plain,
+    Osis,
+    Thml,
+    Gbf,
+    Tei,
+}
+
+/// The versifications of the modules which can be read, by their SWORD name
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ModuleVersification {
+    /// The 66 books of the King James Version
+    Kjv,
+    /// The King James Version with the books of its Apocrypha
+    Kjva,
+    /// The Hebrew numbering and order of the books of the Leningrad Codex
+    Leningrad,
+    /// The Septuagint numbering of the books of the Orthodox canon
+    Lxx,
+}
+
+impl ModuleVersification {
+    /// The numbering of the chapters and verses
+    pub fn versification(&self) -> Versification {
+        match self {
+            ModuleVersification::Kjv | ModuleVersification::Kjva => Versification::Kjv,
+            ModuleVersification::Leningrad => Versification::Hebrew,
+            ModuleVersification::Lxx => Versification::Septuagint,
+        }
+    }
+
+    /// The books of the module, in the order of its index
+    pub fn books(&self) -> Vec<BibleEnum> {
+        match self {
+            ModuleVersification::Kjv => Canon::Protestant.books().to_vec(),
+            ModuleVersification::Kjva => Canon::Protestant
+                .books()
+                .iter()
+                .chain(KJVA_APOCRYPHA)
+                .copied()
+                .collect(),
+            ModuleVersification::Leningrad => LENINGRAD_BOOKS.to_vec(),
+            ModuleVersification::Lxx => Canon::Orthodox.books().to_vec(),
+        }
+    }
+}
+
+/// The books of the Apocrypha of the KJVA, after the Old Testament, without
+/// 2 Esdras which the embedded Bible has not
+const KJVA_APOCRYPHA: &[BibleEnum] = &[
+    FirstEsdras,
+    Tobit,
+    Judith,
+    AdditionsToEsther,
+    WisdomOfSolomon,
+    Sirach,
+    Baruch,
+    PrayerOfAzariah,
+    Susanna,
+    BelAndTheDragon,
+    PrayerOfManasseh,
+    FirstMaccabees,
+    SecondMaccabees,
+];
+
+/// The books of the Leningrad Codex, which has no New Testament
+const LENINGRAD_BOOKS: &[BibleEnum] = &[
+    Genesis,
+    Exodus,
+    Leviticus,
+    Numbers,
+    Deuteronomy,
+    Joshua,
+    Judges,
+    FirstSamuel,
+    SecondSamuel,
+    FirstKings,
+    SecondKings,
+    Isaiah,
+    Jeremiah,
+    Ezekiel,
+    Hosea,
+    Joel,
+    Amos,
+    Obadiah,
+    Jonah,
+    Micah,
+    Nahum,
+    Habakkuk,
+    Zephaniah,
+    Haggai,
+    Zechariah,
+    Malachi,
+    FirstChronicles,
+    SecondChronicles,
+    Psalms,
+    Job,
+    Proverbs,
+    Ruth,
+    SongOfSolomon,
+    Ecclesiastes,
+    Lamentations,
+    Esther,
+    Daniel,
+    Ezra,
+    Nehemiah,
+];
+
+/// The description of a module, its `[Name]` followed by `Key=Value` lines
+#[derive(Debug, Clone, PartialEq)]
+pub struct ModuleConf {
+    pub name: String,
+    /// Every entry, in order, as a key may be repeated
+    pub entries: Vec<(String, String)>,
+}
+
+impl ModuleConf {
+    pub fn parse(source: &str) -> Result<ModuleConf, SwordError> {
+        let mut lines = source
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty() && !line.starts_with('#'));
+
+        let name: String = lines
+            .next()
+            .and_then(|line| line.strip_prefix('['))
+            .and_then(|line| line.strip_suffix(']'))
+            .filter(|name| !name.is_empty())
+            .ok_or_else(|| SwordError::InvalidConf("no [Name] section".to_string()))?
+            .to_string();
+
+        let mut entries: Vec<(String, String)> = Vec::new();
+        // A line ending with a backslash continues on the next one
+        let mut continued: bool = false;
+
+        for line in lines {
+            if continued {
+                if let Some((_, value)) = entries.last_mut() {
+                    value.push('\n');
+                    value.push_str(line.strip_suffix('\\').unwrap_or(line).trim_end());
+                }
+            } else {
+                let (key, value) = line
+                    .split_once('=')
+                    .ok_or_else(|| SwordError::InvalidConf(line.to_string()))?;
+                let value: &str = value.strip_suffix('\\').unwrap_or(value);
+                entries.push((key.trim().to_string(), value.trim().to_string()));
+            }
+
+            continued = line.ends_with('\\');
+        }
+
+        Ok(ModuleConf { name, entries })
+    }
+
+    /// The first value of a key
+    pub fn get(&self, key: &str) -> Option<&str> {
+        self.entries
+            .iter()
+            .find(|(entry_key, _)| entry_key == key)
+            .map(|(_, value)| value.as_str())
+    }
+
+    pub fn description(&self) -> Option<&str> {
+        self.get("Description")
+    }
+
+    pub fn language(&self) -> Option<&str> {
+        self.get("Lang")
+    }
+
+    /// The directory of the module data, relative to the library
+    pub fn data_path(&self) -> Result<PathBuf, SwordError> {
+        let path: &str = self
+            .get("DataPath")
+            .ok_or_else(|| SwordError::InvalidConf(format!("{} has no DataPath", self.name)))?;
+
+        Ok(PathBuf::from(path.trim_start_matches("./")))
+    }
+
+    pub fn driver(&self) -> Result<Driver, SwordError> {
+        match self
+            .get("ModDrv")
+            .unwrap_or_default()
+            .to_lowercase()
+            .as_str()
+        {
+            "rawtext" => Ok(Driver::RawText),
+            "rawtext4" => Ok(Driver::RawText4),
+            "ztext" => Ok(Driver::ZText),
+            "ztext4" => Ok(Driver::ZText4),
+            other => Err(SwordError::UnsupportedDriver(other.to_string())),
+        }
+    }
+
+    /// The versification of the module, the KJV one when none is given
+    pub fn versification(&self) -> Result<ModuleVersification, SwordError> {
+        match self.get("Versification").unwrap_or("KJV") {
+            "KJV" => Ok(ModuleVersification::Kjv),
+            "KJVA" => Ok(ModuleVersification::Kjva),
+            "Leningrad" => Ok(ModuleVersification::Leningrad),
+            "LXX" => Ok(ModuleVersification::Lxx),
+            other => Err(SwordError::UnsupportedVersification(other.to_string())),
+        }
+    }
+
+    pub fn source_type(&self) -> SourceType {
+        match self
+            .get("SourceType")
+            .unwrap_or_default()
+            .to_lowercase()
+            .as_str()
+        {
+            "osis" => SourceType::Osis,
+            "thml" => SourceType::Thml,
+            "gbf" => SourceType::Gbf,
+            "tei" => SourceType::Tei,
+            _ => SourceType::Plain,
+        }
+    }
+
+    /// Whether the text is UTF-8, and not Latin-1 as it is when no encoding is
+    /// given
+    pub fn is_utf8(&self) -> bool {
+        self.get("Encoding")
+            .is_some_and(|encoding| encoding.eq_ignore_ascii_case("UTF-8"))
+    }
+
+    /// Whether the text is compressed with zlib, the only compression read
+    pub(crate) fn check_compression(&self) -> Result<(), SwordError> {
+        match self.get("CompressType").unwrap_or("ZIP") {
+            "ZIP" => Ok(()),
+            other => Err(SwordError::UnsupportedCompression(other.to_string())),
+        }
+    }
+
+    /// Whether the module is locked, its text needing a key to be read
+    pub fn is_encrypted(&self) -> bool {
+        self.get("CipherKey").is_some()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const KJV: &str = "[KJV]
+DataPath=./modules/texts/ztext/kjv/
+ModDrv=zText
+SourceType=OSIS
+Encoding=UTF-8
+CompressType=ZIP
+Lang=en
+Description=King James Version (1769) with Strongs Numbers and Morphology
+GlobalOptionFilter=OSISStrongs
+GlobalOptionFilter=OSISMorph
+About=This is the King James Version of the Holy Bible \\
+(also known as the Authorized Version).
+Versification=KJV
+";
+
+    #[test]
+    fn test_parse() {
+        let conf = ModuleConf::parse(KJV).unwrap();
+
+        assert_eq!(conf.name, "KJV");
+        assert_eq!(
+            conf.data_path().unwrap(),
+            PathBuf::from("modules/texts/ztext/kjv/")
+        );
+        assert_eq!(conf.driver().unwrap(), Driver::ZText);
+        assert_eq!(conf.source_type(), SourceType::Osis);
+        assert_eq!(conf.versification().unwrap(), ModuleVersification::Kjv);
+        assert_eq!(conf.language(), Some("en"));
+        assert_eq!(conf.get("GlobalOptionFilter"), Some("OSISStrongs"));
+        assert_eq!(
+            conf.get("About"),
+            Some("This is the King James Version of the Holy Bible\n(also known as the Authorized Version).")
+        );
+        assert!(conf.is_utf8());
+        assert!(!conf.is_encrypted());
+    }
+
+    #[test]
+    fn test_versification() {
+        let versification = |name: &str| {
+            ModuleConf::parse(&format!("[Test]\nVersification={name}"))
+                .unwrap()
+                .versification()
+                .unwrap()
+        };
+
+        assert_eq!(versification("KJVA").versification(), Versification::Kjv);
+        assert!(versification("KJVA").books().contains(&BibleEnum::Tobit));
+        assert_eq!(
+            versification("Leningrad").versification(),
+            Versification::Hebrew
+        );
+        assert_eq!(versification("Leningrad").books().len(), 39);
+        assert_eq!(
+            versification("LXX").versification(),
+            Versification::Septuagint
+        );
+    }
+
+    #[test]
+    fn test_unsupported() {
+        let conf = ModuleConf::parse("[MHC]\nModDrv=zCom\nVersification=Vulg").unwrap();
+
+        assert!(matches!(
+            conf.driver(),
+            Err(SwordError::UnsupportedDriver(driver)) if driver == "zcom"
+        ));
+        assert!(matches!(
+            conf.versification(),
+            Err(SwordError::UnsupportedVersification(versification)) if versification == "Vulg"
+        ));
+        assert!(conf.data_path().is_err());
+        assert!(ModuleConf::parse("DataPath=./modules").is_err());
+    }
+}
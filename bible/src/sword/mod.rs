@@ -0,0 +1,886 @@
+//! Read the Bible text modules of a SWORD library, like the one of `~/.sword`
+//! shared by the SWORD front ends.
+//!
+//! A library has a `mods.d` directory of `.conf` files describing its modules,
+//! whose data is in the rawText or zText formats: an index per testament
+//! giving where every verse is, in the order of the module versification.
+//! The KJV, KJVA, Leningrad and LXX versifications are read, their verses
+//! being mapped to the KJV versification of the embedded Bible.
+
+use std::{
+    collections::BTreeMap,
+    io::Read,
+    path::{Path, PathBuf},
+};
+
+use book::book_components::{chapter_number::ChapterNumber, verse::Verse};
+use flate2::read::ZlibDecoder;
+
+use crate::{
+    bible_enum::BibleEnum,
+    data_pack::{DataPack, DataPackError, PackMetadata},
+    metadata::Testament,
+    tables,
+    verse_ref::VerseRef,
+    versification::Versification,
+};
+
+pub mod conf;
+
+use conf::{ModuleConf, ModuleVersification, SourceType};
+
+#[derive(Debug, thiserror::Error)]
+pub enum SwordError {
+    #[error("Cannot read {path}")]
+    Io {
+        path: PathBuf,
+        #[source]
+        source: std::io::Error,
+    },
+    #[error("The module configuration is invalid: {0}")]
+    InvalidConf(String),
+    #[error("The module {0} is not installed")]
+    ModuleNotFound(String),
+    #[error("The module driver {0} is not of a Bible text")]
+    UnsupportedDriver(String),
+    #[error("The compression {0} is not supported")]
+    UnsupportedCompression(String),
+    #[error("The versification {0} is not supported")]
+    UnsupportedVersification(String),
+    #[error("The module {0} is encrypted")]
+    Encrypted(String),
+    #[error("The index {0} points outside of the module data")]
+    InvalidIndex(PathBuf),
+    #[error("The module has no text")]
+    NoText,
+    #[error(transparent)]
+    Pack(#[from] DataPackError),
+}
+
+/// The modules installed in a SWORD library
+#[derive(Debug, Clone, PartialEq)]
+pub struct SwordLibrary {
+    pub root: PathBuf,
+    /// The modules of the library, by name
+    pub modules: Vec<ModuleConf>,
+    /// The `.conf` files which cannot be read, left out of the modules
+    pub skipped: Vec<PathBuf>,
+}
+
+impl SwordLibrary {
+    /// The library of `$SWORD_PATH`, or of `~/.sword`
+    pub fn default_root() -> Option<PathBuf> {
+        std::env::var_os("SWORD_PATH")
+            .map(PathBuf::from)
+            .or_else(|| std::env::var_os("HOME").map(|home| Path::new(&home).join(".sword")))
+    }
+
+    /// Read the `.conf` files of the `mods.d` directory of a library, skipping
+    /// those which cannot be read
+    pub fn open(root: &Path) -> Result<SwordLibrary, SwordError> {
+        let mods_d: PathBuf = root.join("mods.d");
+
+        let mut paths: Vec<PathBuf> = std::fs::read_dir(&mods_d)
+            .map_err(io_error(&mods_d))?
+            .map(|entry| entry.map(|entry| entry.path()))
+            .collect::<Result<_, _>>()
+            .map_err(io_error(&mods_d))?;
+        paths.retain(|path| {
+            path.extension()
+                .is_some_and(|extension| extension == "conf")
+        });
+        paths.sort();
+
+        let mut modules: Vec<ModuleConf> = Vec::new();
+        let mut skipped: Vec<PathBuf> = Vec::new();
+        for path in paths {
+            match std::fs::read(&path)
+                .map_err(io_error(&path))
+                .and_then(|source| ModuleConf::parse(&String::from_utf8_lossy(&source)))
+            {
+                Ok(conf) => modules.push(conf),
+                Err(_) => skipped.push(path),
+            }
+        }
+        modules.sort_by(|first, second| first.name.cmp(&second.name));
+
+        Ok(SwordLibrary {
+            root: root.to_path_buf(),
+            modules,
+            skipped,
+        })
+    }
+
+    /// The modules which are Bible texts
+    pub fn bibles(&self) -> impl Iterator<Item = &ModuleConf> {
+        self.modules.iter().filter(|conf| conf.driver().is_ok())
+    }
+
+    /// Open a module by its name, in any case
+    pub fn module(&self, name: &str) -> Result<SwordModule, SwordError> {
+        let conf: &ModuleConf = self
+            .modules
+            .iter()
+            .find(|conf| conf.name.eq_ignore_ascii_case(name))
+            .ok_or_else(|| SwordError::ModuleNotFound(name.to_string()))?;
+
+        SwordModule::open(&self.root, conf.clone())
+    }
+}
+
+/// The verses of a testament: the module data and where every verse is in it
+#[derive(Debug, Clone, PartialEq, Default)]
+struct TestamentData {
+    data: Vec<u8>,
+    /// The start and size of every entry of the index, in the data
+    entries: Vec<(usize, usize)>,
+}
+
+/// A Bible text module, read into memory
+#[derive(Debug, Clone, PartialEq)]
+pub struct SwordModule {
+    pub conf: ModuleConf,
+    layout: Layout,
+    old_testament: Option<TestamentData>,
+    new_testament: Option<TestamentData>,
+}
+
+impl SwordModule {
+    /// Read a module of a library
+    pub fn open(root: &Path, conf: ModuleConf) -> Result<SwordModule, SwordError> {
+        let driver = conf.driver()?;
+        let layout = Layout::new(conf.versification()?);
+
+        if conf.is_encrypted() {
+            return Err(SwordError::Encrypted(conf.name));
+        }
+
+        let directory: PathBuf = root.join(conf.data_path()?);
+        let read = |testament: &str| {
+            if driver.is_compressed() {
+                conf.check_compression()?;
+                read_compressed(&directory, testament, driver.size_width())
+            } else {
+                read_raw(&directory, testament, driver.size_width())
+            }
+        };
+
+        let old_testament = read("ot")?;
+        let new_testament = read("nt")?;
+
+        if old_testament.is_none() && new_testament.is_none() {
+            return Err(SwordError::NoText);
+        }
+
+        Ok(SwordModule {
+            conf,
+            layout,
+            old_testament,
+            new_testament,
+        })
+    }
+
+    /// The text of a verse numbered in the KJV versification, without its
+    /// markup, or `None` if the module does not have it
+    pub fn verse_text(&self, verse_ref: &VerseRef) -> Option<String> {
+        let (testament, index) = self.layout.position(verse_ref)?;
+        let testament_data = match testament {
+            Testament::Old => self.old_testament.as_ref()?,
+            Testament::New => self.new_testament.as_ref()?,
+        };
+
+        let (start, size) = *testament_data.entries.get(index)?;
+        let bytes: &[u8] = &testament_data.data[start..start + size];
+        let markup: String = if self.conf.is_utf8() {
+            String::from_utf8_lossy(bytes).into_owned()
+        } else {
+            // Latin-1 characters are the first of Unicode
+            bytes.iter().map(|byte| *byte as char).collect()
+        };
+
+        let text: String = plain_text(&markup, self.conf.source_type());
+        (!text.is_empty()).then_some(text)
+    }
+
+    /// Build a data pack of the books of the module, in the KJV versification,
+    /// leaving out those without text
+    pub fn to_pack(&self) -> Result<DataPack, SwordError> {
+        let mut pack = DataPack {
+            metadata: PackMetadata {
+                name: self
+                    .conf
+                    .description()
+                    .unwrap_or(&self.conf.name)
+                    .to_string(),
+                language: self.conf.language().unwrap_or("en").to_string(),
+                abbreviation: Some(self.conf.name.clone()),
+                license: self.conf.get("DistributionLicense").map(str::to_string),
+            },
+            versification: Default::default(),
+            text: Default::default(),
+        };
+
+        for book in self.layout.books() {
+            let counts: &[u8] = tables::verse_counts(*book);
+            let text: Vec<Vec<String>> = (1u8..)
+                .zip(counts)
+                .map(|(chapter, count)| {
+                    (1..=*count)
+                        .map(|verse| {
+                            verse_ref(*book, chapter, verse)
+                                .and_then(|verse_ref| self.verse_text(&verse_ref))
+                                .unwrap_or_default()
+                        })
+                        .collect()
+                })
+                .collect();
+
+            if text.iter().flatten().any(|verse| !verse.is_empty()) {
+                pack.versification.insert(*book, counts.to_vec());
+                pack.text.insert(*book, text);
+            }
+        }
+
+        if pack.text.is_empty() {
+            return Err(SwordError::NoText);
+        }
+
+        pack.validate()?;
+
+        Ok(pack)
+    }
+}
+
+/// The verse counts of 2 Esdras, which the KJVA index has after 1 Esdras but
+/// the embedded Bible has not
+const SECOND_ESDRAS_VERSE_COUNTS: &[u8] = &[
+    40, 48, 36, 52, 56, 59, 70, 63, 47, 59, 46, 51, 58, 48, 63, 78,
+];
+
+/// Where the verses of a module are in the indexes of its testaments.
+///
+/// Every testament starts with the heading of the module and its own heading,
+/// and every book and chapter with its heading, before the verses, in the
+/// order and the numbering of the module versification.
+#[derive(Debug, Clone, PartialEq)]
+struct Layout {
+    versification: Versification,
+    /// For every book, its testament and the index of the heading and the
+    /// verse count of every chapter
+    books: BTreeMap<BibleEnum, (Testament, Vec<(usize, u8)>)>,
+}
+
+impl Layout {
+    fn new(module_versification: ModuleVersification) -> Layout {
+        let versification: Versification = module_versification.versification();
+        let module_books: Vec<BibleEnum> = module_versification.books();
+        let mut books = BTreeMap::new();
+
+        for testament in [Testament::Old, Testament::New] {
+            // The heading of the testament
+            let mut index: usize = 1;
+            let mut add_book = |verse_counts: &[u8]| -> Vec<(usize, u8)> {
+                // The heading of the book
+                index += 1;
+
+                verse_counts
+                    .iter()
+                    .map(|count| {
+                        let heading: usize = index + 1;
+                        index += *count as usize + 1;
+                        (heading, *count)
+                    })
+                    .collect()
+            };
+
+            for book in module_books
+                .iter()
+                .filter(|book| book.testament() == testament)
+            {
+                let chapters = add_book(&versification.verse_counts(*book));
+                books.insert(*book, (testament, chapters));
+
+                if module_versification == ModuleVersification::Kjva
+                    && *book == BibleEnum::FirstEsdras
+                {
+                    add_book(SECOND_ESDRAS_VERSE_COUNTS);
+                }
+            }
+        }
+
+        Layout {
+            versification,
+            books,
+        }
+    }
+
+    /// The books of the module, in the order of the Bible
+    fn books(&self) -> impl Iterator<Item = &BibleEnum> {
+        self.books.keys()
+    }
+
+    /// The testament and the position in its index of a verse numbered in the
+    /// KJV versification
+    fn position(&self, verse_ref: &VerseRef) -> Option<(Testament, usize)> {
+        let verse_ref: VerseRef = Versification::Kjv
+            .map_to(self.versification, *verse_ref)
+            .ok()?;
+        let (testament, chapters) = self.books.get(&verse_ref.book)?;
+        let (heading, count) = *chapters.get(u8::from(verse_ref.chapter) as usize - 1)?;
+        let verse: u8 = u8::from(verse_ref.verse);
+
+        (verse <= count).then_some((*testament, heading + verse as usize))
+    }
+}
+
+/// Read the `ot` or `nt` data of a rawText module, and its `.vss` index of
+/// 32 bit starts and sizes
+fn read_raw(
+    directory: &Path,
+    testament: &str,
+    size_width: usize,
+) -> Result<Option<TestamentData>, SwordError> {
+    let Some(data) = read_optional(&directory.join(testament))? else {
+        return Ok(None);
+    };
+    let index_path: PathBuf = directory.join(format!("{testament}.vss"));
+    let index: Vec<u8> = std::fs::read(&index_path).map_err(io_error(&index_path))?;
+
+    let entries: Vec<(usize, usize)> = index
+        .chunks_exact(4 + size_width)
+        .map(|entry| (read_number(&entry[..4]), read_number(&entry[4..])))
+        .collect();
+    check_entries(&entries, data.len(), &index_path)?;
+
+    Ok(Some(TestamentData { data, entries }))
+}
+
+/// Read the `.bzz` blocks of a zText module, decompressed with the `.bzs`
+/// index of blocks, and its `.bzv` index of verses in the blocks
+fn read_compressed(
+    directory: &Path,
+    testament: &str,
+    size_width: usize,
+) -> Result<Option<TestamentData>, SwordError> {
+    let blocks_path: PathBuf = directory.join(format!("{testament}.bzz"));
+    let Some(compressed) = read_optional(&blocks_path)? else {
+        return Ok(None);
+    };
+    let block_index_path: PathBuf = directory.join(format!("{testament}.bzs"));
+    let block_index: Vec<u8> =
+        std::fs::read(&block_index_path).map_err(io_error(&block_index_path))?;
+    let verse_index_path: PathBuf = directory.join(format!("{testament}.bzv"));
+    let verse_index: Vec<u8> =
+        std::fs::read(&verse_index_path).map_err(io_error(&verse_index_path))?;
+
+    // The blocks are decompressed one after another, each starting where the
+    // previous one ends
+    let mut data: Vec<u8> = Vec::new();
+    let mut block_starts: Vec<usize> = Vec::new();
+
+    for entry in block_index.chunks_exact(12) {
+        let start: usize = read_number(&entry[..4]);
+        let size: usize = read_number(&entry[4..8]);
+        let block: &[u8] = compressed
+            .get(start..start + size)
+            .ok_or_else(|| SwordError::InvalidIndex(block_index_path.clone()))?;
+
+        block_starts.push(data.len());
+        ZlibDecoder::new(block)
+            .read_to_end(&mut data)
+            .map_err(io_error(&blocks_path))?;
+    }
+
+    let entries: Vec<(usize, usize)> = verse_index
+        .chunks_exact(8 + size_width)
+        .map(|entry| {
+            let size: usize = read_number(&entry[8..]);
+            if size == 0 {
+                return Some((0, 0));
+            }
+
+            let block_start: usize = *block_starts.get(read_number(&entry[..4]))?;
+            Some((block_start + read_number(&entry[4..8]), size))
+        })
+        .collect::<Option<_>>()
+        .ok_or_else(|| SwordError::InvalidIndex(verse_index_path.clone()))?;
+    check_entries(&entries, data.len(), &verse_index_path)?;
+
+    Ok(Some(TestamentData { data, entries }))
+}
+
+/// Read a file, or nothing if the module has no such testament
+fn read_optional(path: &Path) -> Result<Option<Vec<u8>>, SwordError> {
+    match std::fs::read(path) {
+        Ok(data) => Ok(Some(data)),
+        Err(error) if error.kind() == std::io::ErrorKind::NotFound => Ok(None),
+        Err(error) => Err(io_error(path)(error)),
+    }
+}
+
+/// Read a little-endian number of 2 or 4 bytes
+fn read_number(bytes: &[u8]) -> usize {
+    bytes
+        .iter()
+        .rev()
+        .fold(0, |number, byte| number << 8 | *byte as usize)
+}
+
+fn check_entries(entries: &[(usize, usize)], length: usize, path: &Path) -> Result<(), SwordError> {
+    if entries.iter().any(|(start, size)| start + size > length) {
+        return Err(SwordError::InvalidIndex(path.to_path_buf()));
+    }
+
+    Ok(())
+}
+
+/// Remove the markup of a verse, with its notes and headings
+fn plain_text(markup: &str, source_type: SourceType) -> String {
+    if source_type == SourceType::Plain {
+        return markup.split_whitespace().collect::<Vec<&str>>().join(" ");
+    }
+
+    let mut text: String = String::new();
+    // The depth of the element being skipped, like a note
+    let mut skipped_depth: usize = 0;
+    let mut rest: &str = markup;
+
+    while let Some(start) = rest.find('<') {
+        if skipped_depth == 0 {
+            text.push_str(&rest[..start]);
+        }
+
+        let Some(length) = rest[start..].find('>') else {
+            rest = &rest[start..];
+            break;
+        };
+        let tag: &str = &rest[start + 1..start + length];
+        rest = &rest[start + length + 1..];
+
+        match tag_kind(tag) {
+            Tag::SkippedStart => skipped_depth += 1,
+            Tag::SkippedEnd => skipped_depth = skipped_depth.saturating_sub(1),
+            Tag::Break if skipped_depth == 0 => text.push(' '),
+            _ => {}
+        }
+    }
+
+    if skipped_depth == 0 {
+        text.push_str(rest);
+    }
+
+    let text: String = match quick_xml::escape::unescape(&text) {
+        Ok(unescaped) => unescaped.into_owned(),
+        // Like the &nbsp; of ThML, which is not an XML entity
+        Err(_) => text.replace("&nbsp;", " "),
+    };
+
+    text.split_whitespace().collect::<Vec<&str>>().join(" ")
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Tag {
+    /// The start of an element whose text is not part of the verse
+    SkippedStart,
+    SkippedEnd,
+    /// An element separating words, like a line of poetry
+    Break,
+    Other,
+}
+
+/// The kind of a tag of OSIS, ThML or GBF, whose end tags change the case of
+/// their last letter, like `<RF>` and `<Rf>`
+fn tag_kind(tag: &str) -> Tag {
+    let (is_end, tag) = match tag.strip_prefix('/') {
+        Some(tag) => (true, tag),
+        None => (false, tag),
+    };
+    let is_empty: bool = tag.ends_with('/');
+    let name: &str = tag
+        .split(|character: char| character.is_whitespace() || character == '/')
+        .next()
+        .unwrap_or_default();
+
+    match name {
+        "note" | "title" | "scripRef" if is_end => Tag::SkippedEnd,
+        "note" | "title" | "scripRef" if !is_empty => Tag::SkippedStart,
+        "RF" | "TS" => Tag::SkippedStart,
+        "Rf" | "Ts" => Tag::SkippedEnd,
+        "l" | "lg" | "lb" | "p" | "br" | "milestone" | "CM" | "CL" => Tag::Break,
+        _ => Tag::Other,
+    }
+}
+
+fn verse_ref(book: BibleEnum, chapter: u8, verse: u8) -> Option<VerseRef> {
+    Some(VerseRef::new(
+        book,
+        ChapterNumber::try_from(chapter).ok()?,
+        Verse::try_from(verse).ok()?,
+    ))
+}
+
+fn io_error(path: &Path) -> impl FnOnce(std::io::Error) -> SwordError {
+    let path = path.to_path_buf();
+    move |source| SwordError::Io { path, source }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Write;
+
+    use flate2::{write::ZlibEncoder, Compression};
+
+    use super::*;
+
+    fn verse(book: BibleEnum, chapter: u8, verse: u8) -> VerseRef {
+        verse_ref(book, chapter, verse).unwrap()
+    }
+
+    /// The entries of a testament index, with the text of some verses
+    fn entries(layout: &Layout, testament: Testament, verses: &[(VerseRef, &str)]) -> Vec<Vec<u8>> {
+        let entry_count: usize = layout
+            .books
+            .values()
+            .filter(|(book_testament, _)| *book_testament == testament)
+            .flat_map(|(_, chapters)| chapters)
+            .map(|(heading, count)| heading + *count as usize + 1)
+            .max()
+            .unwrap_or_default();
+        let mut entries: Vec<Vec<u8>> = vec![Vec::new(); entry_count];
+
+        for (verse_ref, text) in verses {
+            if let Some((verse_testament, index)) = layout.position(verse_ref) {
+                if verse_testament == testament {
+                    entries[index] = text.as_bytes().to_vec();
+                }
+            }
+        }
+
+        entries
+    }
+
+    fn write_raw(directory: &Path, testament: &str, entries: &[Vec<u8>]) {
+        let mut data: Vec<u8> = Vec::new();
+        let mut index: Vec<u8> = Vec::new();
+
+        for entry in entries {
+            index.extend((data.len() as u32).to_le_bytes());
+            index.extend((entry.len() as u16).to_le_bytes());
+            data.extend(entry);
+        }
+
+        std::fs::write(directory.join(testament), data).unwrap();
+        std::fs::write(directory.join(format!("{testament}.vss")), index).unwrap();
+    }
+
+    /// Write a zText testament, a block per 1000 entries
+    fn write_compressed(directory: &Path, testament: &str, entries: &[Vec<u8>]) {
+        let mut compressed: Vec<u8> = Vec::new();
+        let mut block_index: Vec<u8> = Vec::new();
+        let mut verse_index: Vec<u8> = Vec::new();
+
+        for (block_number, block_entries) in entries.chunks(1000).enumerate() {
+            let mut block: Vec<u8> = Vec::new();
+
+            for entry in block_entries {
+                verse_index.extend((block_number as u32).to_le_bytes());
+                verse_index.extend((block.len() as u32).to_le_bytes());
+                verse_index.extend((entry.len() as u16).to_le_bytes());
+                block.extend(entry);
+            }
+
+            let mut encoder = ZlibEncoder::new(Vec::new(), Compression::default());
+            encoder.write_all(&block).unwrap();
+            let block_data: Vec<u8> = encoder.finish().unwrap();
+
+            block_index.extend((compressed.len() as u32).to_le_bytes());
+            block_index.extend((block_data.len() as u32).to_le_bytes());
+            block_index.extend((block.len() as u32).to_le_bytes());
+            compressed.extend(block_data);
+        }
+
+        std::fs::write(directory.join(format!("{testament}.bzz")), compressed).unwrap();
+        std::fs::write(directory.join(format!("{testament}.bzs")), block_index).unwrap();
+        std::fs::write(directory.join(format!("{testament}.bzv")), verse_index).unwrap();
+    }
+
+    #[test]
+    fn test_layout() {
+        let kjv = Layout::new(ModuleVersification::Kjv);
+
+        assert_eq!(
+            kjv.position(&verse(BibleEnum::Genesis, 1, 1)),
+            Some((Testament::Old, 4))
+        );
+        assert_eq!(
+            kjv.position(&verse(BibleEnum::Genesis, 2, 1)),
+            Some((Testament::Old, 4 + 31 + 1))
+        );
+        assert_eq!(
+            kjv.position(&verse(BibleEnum::Exodus, 1, 1)),
+            Some((Testament::Old, 4 + 1533 + 50 + 1))
+        );
+        assert_eq!(
+            kjv.position(&verse(BibleEnum::Matthew, 1, 1)),
+            Some((Testament::New, 4))
+        );
+        assert_eq!(kjv.position(&verse(BibleEnum::Genesis, 1, 32)), None);
+        assert_eq!(kjv.position(&verse(BibleEnum::Tobit, 1, 1)), None);
+
+        // The Apocrypha follow the Old Testament, 2 Esdras after 1 Esdras
+        let kjva = Layout::new(ModuleVersification::Kjva);
+        let after_malachi: usize = kjv.position(&verse(BibleEnum::Malachi, 4, 6)).unwrap().1;
+        let first_esdras: usize = after_malachi
+            + 1
+            + tables::verse_counts(BibleEnum::FirstEsdras)
+                .iter()
+                .map(|count| *count as usize + 1)
+                .sum::<usize>();
+        let second_esdras: usize = 1 + SECOND_ESDRAS_VERSE_COUNTS
+            .iter()
+            .map(|count| *count as usize + 1)
+            .sum::<usize>();
+        assert_eq!(
+            kjva.position(&verse(BibleEnum::Tobit, 1, 1)),
+            Some((Testament::Old, first_esdras + second_esdras + 3))
+        );
+        assert_eq!(
+            kjva.position(&verse(BibleEnum::Matthew, 1, 1)),
+            Some((Testament::New, 4))
+        );
+
+        // Malachi 4 is the end of Malachi 3, and the Leningrad Codex has no
+        // New Testament
+        let leningrad = Layout::new(ModuleVersification::Leningrad);
+        let malachi_3_19 = leningrad.position(&verse(BibleEnum::Malachi, 4, 1));
+        assert_eq!(
+            leningrad.position(&verse(BibleEnum::Malachi, 3, 18)),
+            malachi_3_19.map(|(testament, index)| (testament, index - 1))
+        );
+        assert!(
+            leningrad
+                .position(&verse(BibleEnum::Malachi, 1, 1))
+                .unwrap()
+                .1
+                < leningrad
+                    .position(&verse(BibleEnum::Psalms, 1, 1))
+                    .unwrap()
+                    .1
+        );
+        assert_eq!(leningrad.position(&verse(BibleEnum::Matthew, 1, 1)), None);
+
+        let lxx = Layout::new(ModuleVersification::Lxx);
+        assert_eq!(
+            lxx.position(&verse(BibleEnum::Psalms, 10, 1)),
+            lxx.position(&verse(BibleEnum::Psalms, 9, 20))
+                .map(|(testament, index)| (testament, index + 1))
+        );
+        assert!(lxx
+            .position(&verse(BibleEnum::FourthMaccabees, 1, 1))
+            .is_some());
+    }
+
+    #[test]
+    fn test_plain_text() {
+        assert_eq!(
+            plain_text(
+                r#"<w lemma="strong:H07225">In the beginning</w> God<note type="x-strongsMarkup">H430</note> created &amp; <title type="psalm">A Psalm</title><lb/>made"#,
+                SourceType::Osis
+            ),
+            "In the beginning God created & made"
+        );
+        assert_eq!(
+            plain_text(
+                "In the beginning<RF>Or, first<Rf> God<CM>created",
+                SourceType::Gbf
+            ),
+            "In the beginning God created"
+        );
+        assert_eq!(
+            plain_text("In&nbsp;the <note>1</note>beginning", SourceType::Thml),
+            "In the beginning"
+        );
+    }
+
+    #[test]
+    fn test_library() {
+        let root =
+            std::env::temp_dir().join(format!("bible_read_test_sword_{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&root);
+        std::fs::create_dir_all(root.join("mods.d")).unwrap();
+        std::fs::write(
+            root.join("mods.d/kjv.conf"),
+            "[KJV]\nDataPath=./modules/texts/ztext/kjv/\nModDrv=zText\nSourceType=OSIS\nEncoding=UTF-8\nCompressType=ZIP\nDescription=King James Version\nLang=en\nVersification=KJV\n",
+        )
+        .unwrap();
+        std::fs::write(
+            root.join("mods.d/web.conf"),
+            "[WEB]\nDataPath=./modules/texts/rawtext/web/\nModDrv=RawText\nLang=fr\n",
+        )
+        .unwrap();
+        std::fs::write(
+            root.join("mods.d/mhc.conf"),
+            "[MHC]\nDataPath=./modules/comments/zcom/mhc/\nModDrv=zCom\n",
+        )
+        .unwrap();
+        std::fs::write(root.join("mods.d/broken.conf"), "DataPath=./modules/\n").unwrap();
+
+        let verses = [
+            (
+                verse(BibleEnum::Genesis, 1, 1),
+                r#"<w lemma="strong:H07225">In the beginning</w> God created the heaven and the earth."#,
+            ),
+            (
+                verse(BibleEnum::Malachi, 4, 6),
+                "Lest I come and smite the earth with a curse.",
+            ),
+            (verse(BibleEnum::John, 11, 35), "Jesus wept."),
+            (verse(BibleEnum::Revelation, 22, 21), "Amen."),
+        ];
+
+        let kjv_directory = root.join("modules/texts/ztext/kjv");
+        std::fs::create_dir_all(&kjv_directory).unwrap();
+        let kjv_layout = Layout::new(ModuleVersification::Kjv);
+        write_compressed(
+            &kjv_directory,
+            "ot",
+            &entries(&kjv_layout, Testament::Old, &verses),
+        );
+        write_compressed(
+            &kjv_directory,
+            "nt",
+            &entries(&kjv_layout, Testament::New, &verses),
+        );
+
+        // A New Testament in Latin-1
+        let web_directory = root.join("modules/texts/rawtext/web");
+        std::fs::create_dir_all(&web_directory).unwrap();
+        let mut web_entries = entries(&kjv_layout, Testament::New, &[]);
+        web_entries[kjv_layout
+            .position(&verse(BibleEnum::John, 11, 35))
+            .unwrap()
+            .1] = b"J\xe9sus pleura.".to_vec();
+        write_raw(&web_directory, "nt", &web_entries);
+
+        let library = SwordLibrary::open(&root).unwrap();
+        let kjv = library.module("kjv").unwrap();
+        let web = library.module("WEB").unwrap();
+        let missing = library.module("ESV");
+        std::fs::remove_dir_all(&root).unwrap();
+
+        assert_eq!(
+            library
+                .bibles()
+                .map(|conf| conf.name.as_str())
+                .collect::<Vec<&str>>(),
+            vec!["KJV", "WEB"]
+        );
+        assert!(matches!(missing, Err(SwordError::ModuleNotFound(_))));
+        assert_eq!(library.skipped, vec![root.join("mods.d/broken.conf")]);
+
+        assert_eq!(
+            kjv.verse_text(&verse(BibleEnum::Genesis, 1, 1)).unwrap(),
+            "In the beginning God created the heaven and the earth."
+        );
+        assert_eq!(
+            kjv.verse_text(&verse(BibleEnum::Revelation, 22, 21))
+                .unwrap(),
+            "Amen."
+        );
+        assert_eq!(kjv.verse_text(&verse(BibleEnum::Genesis, 1, 2)), None);
+        assert_eq!(
+            web.verse_text(&verse(BibleEnum::John, 11, 35)).unwrap(),
+            "Jésus pleura."
+        );
+        assert_eq!(web.verse_text(&verse(BibleEnum::Genesis, 1, 1)), None);
+
+        let pack = kjv.to_pack().unwrap();
+        assert_eq!(pack.metadata.name, "King James Version");
+        assert_eq!(
+            pack.versification
+                .keys()
+                .copied()
+                .collect::<Vec<BibleEnum>>(),
+            vec![
+                BibleEnum::Genesis,
+                BibleEnum::Malachi,
+                BibleEnum::John,
+                BibleEnum::Revelation
+            ]
+        );
+
+        let bible = crate::Bible::from_sword(&web).unwrap();
+        assert_eq!(bible.metadata().language, "fr");
+        assert_eq!(
+            bible
+                .verse_text(
+                    BibleEnum::John,
+                    11u8.try_into().unwrap(),
+                    35u8.try_into().unwrap()
+                )
+                .unwrap(),
+            "Jésus pleura."
+        );
+    }
+
+    #[test]
+    fn test_other_versifications() {
+        let root = std::env::temp_dir().join(format!(
+            "bible_read_test_sword_versifications_{}",
+            std::process::id()
+        ));
+        let _ = std::fs::remove_dir_all(&root);
+        std::fs::create_dir_all(root.join("mods.d")).unwrap();
+
+        let verses = [
+            (verse(BibleEnum::Genesis, 1, 1), "In the beginning."),
+            (verse(BibleEnum::Malachi, 4, 6), "Lest I come."),
+            (
+                verse(BibleEnum::Tobit, 1, 1),
+                "The book of the words of Tobit.",
+            ),
+        ];
+
+        for (name, versification) in [
+            ("KJVA", ModuleVersification::Kjva),
+            ("Leningrad", ModuleVersification::Leningrad),
+        ] {
+            let directory = root.join("modules/texts/rawtext").join(name);
+            std::fs::create_dir_all(&directory).unwrap();
+            std::fs::write(
+                root.join(format!("mods.d/{name}.conf")),
+                format!("[{name}]\nDataPath=./modules/texts/rawtext/{name}/\nModDrv=RawText\nVersification={name}\n"),
+            )
+            .unwrap();
+
+            let layout = Layout::new(versification);
+            write_raw(&directory, "ot", &entries(&layout, Testament::Old, &verses));
+        }
+
+        let library = SwordLibrary::open(&root).unwrap();
+        let kjva = library.module("KJVA").unwrap();
+        let leningrad = library.module("Leningrad").unwrap();
+        std::fs::remove_dir_all(&root).unwrap();
+
+        assert_eq!(
+            kjva.to_pack()
+                .unwrap()
+                .versification
+                .keys()
+                .copied()
+                .collect::<Vec<BibleEnum>>(),
+            vec![BibleEnum::Genesis, BibleEnum::Malachi, BibleEnum::Tobit]
+        );
+        assert_eq!(
+            kjva.verse_text(&verse(BibleEnum::Tobit, 1, 1)).unwrap(),
+            "The book of the words of Tobit."
+        );
+
+        let pack = leningrad.to_pack().unwrap();
+        assert_eq!(
+            pack.text[&BibleEnum::Malachi][3][5],
+            "Lest I come.".to_string()
+        );
+        assert!(!pack.text.contains_key(&BibleEnum::Tobit));
+    }
+}
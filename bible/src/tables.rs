@@ -0,0 +1,205 @@
+//! The versification and text of the embedded Bible, compiled by the build
+//! script from `bible.json` and `bible_text.json`.
+//!
+//! The lookups read static tables and never allocate. The default [`crate::Bible`]
+//! answers with them, rather than with an index of its verses.
+
+#[cfg(test)]
+use std::collections::BTreeMap;
+
+use book::book_components::{chapter_number::ChapterNumber, verse::Verse};
+use strum::IntoEnumIterator;
+
+use crate::{bible_enum::BibleEnum, verse_ref::VerseRef};
+
+include!(concat!(env!("OUT_DIR"), "/tables.rs"));
+
+/// The number of verses of every chapter of a book, a count of zero marking a
+/// missing chapter
+pub fn verse_counts(book: BibleEnum) -> &'static [u8] {
+    VERSE_COUNTS[book as usize]
+}
+
+/// The number of chapters of a book, without its missing chapters
+pub fn chapter_count(book: BibleEnum) -> usize {
+    verse_counts(book)
+        .iter()
+        .filter(|count| **count > 0)
+        .count()
+}
+
+/// The last chapter of a book
+pub fn last_chapter(book: BibleEnum) -> ChapterNumber {
+    ChapterNumber::try_from(verse_counts(book).len() as u8)
+        .expect("The embedded chapter counts are valid")
+}
+
+/// The last verse of a chapter, if the book has this chapter
+pub fn max_verse(book: BibleEnum, chapter: ChapterNumber) -> Option<Verse> {
+    let count = *verse_counts(book).get(u8::from(chapter) as usize - 1)?;
+
+    Verse::try_from(count).ok()
+}
+
+/// Check that a verse is in the embedded Bible
+pub fn contains(verse_ref: &VerseRef) -> bool {
+    ordinal(verse_ref).is_some()
+}
+
+/// The position of a verse in the embedded Bible, the first verse being 1
+pub fn ordinal(verse_ref: &VerseRef) -> Option<u32> {
+    let index = u8::from(verse_ref.chapter) as usize - 1;
+    let count = *VERSE_COUNTS[verse_ref.book as usize].get(index)?;
+    let verse = u8::from(verse_ref.verse);
+
+    (verse <= count).then(|| CHAPTER_OFFSETS[verse_ref.book as usize][index] + verse as u32)
+}
+
+/// The verse at a position in the embedded Bible, the first verse being 1
+pub fn verse_at(ordinal: u32) -> Option<VerseRef> {
+    if ordinal == 0 || ordinal > VERSE_TOTAL {
+        return None;
+    }
+
+    // A missing chapter or book has the offset of the next one, which is found
+    let book = CHAPTER_OFFSETS.partition_point(|offsets| offsets[0] < ordinal) - 1;
+    let offsets = CHAPTER_OFFSETS[book];
+    let chapter = offsets.partition_point(|offset| *offset < ordinal) - 1;
+
+    Some(VerseRef::new(
+        BibleEnum::iter().nth(book)?,
+        ChapterNumber::try_from(chapter as u8 + 1).ok()?,
+        Verse::try_from((ordinal - offsets[chapter]) as u8).ok()?,
+    ))
+}
+
+/// The number of verses of the embedded Bible
+pub fn verse_total() -> u32 {
+    VERSE_TOTAL
+}
+
+/// The text of a verse, if the embedded Bible has it
+pub fn verse_text(verse_ref: &VerseRef) -> Option<&'static str> {
+    VERSE_TEXT[verse_ref.book as usize]
+        .get(u8::from(verse_ref.chapter) as usize - 1)?
+        .get(u8::from(verse_ref.verse) as usize - 1)
+        .copied()
+}
+
+/// The text of every verse of a chapter, empty if the embedded Bible has not
+/// its text
+pub fn chapter_text(book: BibleEnum, chapter: ChapterNumber) -> &'static [&'static str] {
+    VERSE_TEXT[book as usize]
+        .get(u8::from(chapter) as usize - 1)
+        .copied()
+        .unwrap_or_default()
+}
+
+/// The versification of every book, as in a data pack
+#[cfg(test)]
+pub(crate) fn versification() -> BTreeMap<BibleEnum, Vec<u8>> {
+    BibleEnum::iter()
+        .map(|book| (book, verse_counts(book).to_vec()))
+        .collect()
+}
+
+/// The text of every book having one, as in a data pack
+#[cfg(test)]
+pub(crate) fn text() -> BTreeMap<BibleEnum, Vec<Vec<String>>> {
+    BibleEnum::iter()
+        .filter(|book| !VERSE_TEXT[*book as usize].is_empty())
+        .map(|book| {
+            (
+                book,
+                VERSE_TEXT[book as usize]
+                    .iter()
+                    .map(|chapter| chapter.iter().map(|verse| verse.to_string()).collect())
+                    .collect(),
+            )
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{canon::Canon, data_pack::DataPack, init_bible::default_metadata, Bible};
+
+    fn verse(book: BibleEnum, chapter: u8, verse: u8) -> VerseRef {
+        VerseRef::new(book, chapter.try_into().unwrap(), verse.try_into().unwrap())
+    }
+
+    #[test]
+    fn test_tables_match_the_bible() {
+        let bible = Bible::from_pack(DataPack {
+            metadata: default_metadata(),
+            versification: versification(),
+            text: text(),
+        })
+        .unwrap();
+
+        assert_eq!(verse_total(), bible.verse_count());
+
+        for book in BibleEnum::iter() {
+            assert_eq!(chapter_count(book), bible[book].chapter_count());
+            assert_eq!(
+                last_chapter(book),
+                *bible[book].chapters.last().unwrap().get_chapter_number()
+            );
+        }
+
+        for ordinal in 1..=verse_total() {
+            let verse_ref = bible.verse_at(ordinal).unwrap();
+            assert_eq!(verse_at(ordinal), Some(verse_ref));
+            assert_eq!(super::ordinal(&verse_ref), Some(ordinal));
+        }
+        assert_eq!(verse_at(0), None);
+        assert_eq!(verse_at(verse_total() + 1), None);
+    }
+
+    #[test]
+    fn test_lookups() {
+        assert_eq!(verse_counts(BibleEnum::Genesis)[0], 31);
+        assert_eq!(
+            max_verse(BibleEnum::Psalms, 119u8.try_into().unwrap()),
+            Some(176u8.try_into().unwrap())
+        );
+        assert_eq!(
+            max_verse(BibleEnum::AdditionsToEsther, 1u8.try_into().unwrap()),
+            None
+        );
+        assert_eq!(ordinal(&verse(BibleEnum::Genesis, 2, 1)), Some(32));
+        assert_eq!(ordinal(&verse(BibleEnum::Revelation, 22, 21)), Some(31102));
+        assert!(!contains(&verse(BibleEnum::Genesis, 1, 32)));
+        assert!(!contains(&verse(BibleEnum::Genesis, 51, 1)));
+        assert_eq!(
+            verse_text(&verse(BibleEnum::Genesis, 1, 1)),
+            Some("In the beginning God created the heaven and the earth.")
+        );
+    }
+
+    #[test]
+    #[ignore = "bible_text.json holds only Genesis 1 until the full King James Version is imported"]
+    fn test_every_book_has_text() {
+        assert_eq!(
+            verse_text(&verse(BibleEnum::Malachi, 4, 6)),
+            Some("And he shall turn the heart of the fathers to the children, and the heart of the children to their fathers, lest I come and smite the earth with a curse.")
+        );
+        assert_eq!(
+            verse_text(&verse(BibleEnum::Revelation, 22, 21)),
+            Some("The grace of our Lord Jesus Christ be with you all. Amen.")
+        );
+
+        for book in Canon::Protestant.books() {
+            for (chapter, count) in (1u8..).zip(verse_counts(*book)) {
+                let chapter = ChapterNumber::try_from(chapter).unwrap();
+
+                assert_eq!(
+                    chapter_text(*book, chapter).len(),
+                    *count as usize,
+                    "{book:?} {chapter:?}"
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,66 @@
+use std::collections::BTreeMap;
+
+use book::{book_components::verse::Verse, Book};
+use strum::IntoEnumIterator;
+
+use crate::{bible_enum::BibleEnum, verse_ref::VerseRef};
+
+/// The ordinals of the verses of a Bible, numbered from 1 in the order of the
+/// books, chapters and verses
+#[derive(Debug, Default)]
+pub(crate) struct VerseIndex {
+    /// For every book, by its number, and every chapter, by its number, the
+    /// ordinal before the first verse of the chapter and its verse count
+    chapters: Vec<Vec<Option<(u32, u8)>>>,
+    /// Every verse, the verse of ordinal 1 being the first
+    verses: Vec<VerseRef>,
+}
+
+impl VerseIndex {
+    pub(crate) fn new(books: &BTreeMap<BibleEnum, Book>) -> VerseIndex {
+        let mut chapters: Vec<Vec<Option<(u32, u8)>>> = vec![Vec::new(); BibleEnum::iter().count()];
+        let mut verses: Vec<VerseRef> = Vec::new();
+
+        for (book, content) in books {
+            let book_chapters = &mut chapters[*book as usize];
+
+            for chapter in &content.chapters {
+                let number = u8::from(chapter.get_chapter_number()) as usize;
+                let count = u8::from(chapter.get_max_verse());
+
+                if book_chapters.len() <= number {
+                    book_chapters.resize(number + 1, None);
+                }
+                book_chapters[number] = Some((verses.len() as u32, count));
+
+                verses.extend((1..=count).filter_map(|verse| {
+                    Some(VerseRef::new(
+                        *book,
+                        *chapter.get_chapter_number(),
+                        Verse::try_from(verse).ok()?,
+                    ))
+                }));
+            }
+        }
+
+        VerseIndex { chapters, verses }
+    }
+
+    pub(crate) fn ordinal(&self, verse_ref: &VerseRef) -> Option<u32> {
+        let (offset, count) = (*self
+            .chapters
+            .get(verse_ref.book as usize)?
+            .get(u8::from(verse_ref.chapter) as usize)?)?;
+        let verse = u8::from(verse_ref.verse);
+
+        (verse <= count).then_some(offset + verse as u32)
+    }
+
+    pub(crate) fn verse(&self, ordinal: u32) -> Option<VerseRef> {
+        self.verses.get(ordinal.checked_sub(1)? as usize).copied()
+    }
+
+    pub(crate) fn len(&self) -> u32 {
+        self.verses.len() as u32
+    }
+}
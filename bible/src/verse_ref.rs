@@ -0,0 +1,114 @@
+use book::book_components::{chapter_number::ChapterNumber, verse::Verse};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    bible_enum::BibleEnum,
+    locale::{Locale, LocalizedDisplay},
+};
+
+/// A reference to a single verse
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy, Serialize, Deserialize)]
+pub struct VerseRef {
+    pub book: BibleEnum,
+    pub chapter: ChapterNumber,
+    pub verse: Verse,
+}
+
+#[derive(Debug, thiserror::Error, PartialEq)]
+#[error("{0} is not a packed verse reference")]
+pub struct PackedVerseError(u32);
+
+impl VerseRef {
+    pub fn new(book: BibleEnum, chapter: ChapterNumber, verse: Verse) -> VerseRef {
+        VerseRef {
+            book,
+            chapter,
+            verse,
+        }
+    }
+
+    /// Encode the reference in an integer written BBCCCVVV, the book being
+    /// numbered from 1, which sorts as the references do
+    pub fn to_packed(&self) -> u32 {
+        (self.book as u32 + 1) * 1_000_000
+            + u8::from(self.chapter) as u32 * 1_000
+            + u8::from(self.verse) as u32
+    }
+
+    /// Decode a reference written BBCCCVVV
+    pub fn from_packed(packed: u32) -> Result<VerseRef, PackedVerseError> {
+        let error = || PackedVerseError(packed);
+
+        let book = (packed / 1_000_000)
+            .checked_sub(1)
+            .and_then(|index| BibleEnum::from_repr(index as usize))
+            .ok_or_else(error)?;
+        let chapter = u8::try_from(packed / 1_000 % 1_000)
+            .ok()
+            .and_then(|chapter| ChapterNumber::try_from(chapter).ok())
+            .ok_or_else(error)?;
+        let verse = u8::try_from(packed % 1_000)
+            .ok()
+            .and_then(|verse| Verse::try_from(verse).ok())
+            .ok_or_else(error)?;
+
+        Ok(VerseRef::new(book, chapter, verse))
+    }
+}
+
+impl std::fmt::Display for VerseRef {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.fmt_localized(&Locale::English, f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_display() {
+        let verse_ref = VerseRef::new(
+            BibleEnum::FirstSamuel,
+            3u8.try_into().unwrap(),
+            10u8.try_into().unwrap(),
+        );
+
+        assert_eq!(verse_ref.to_string(), "1 Samuel 3:10");
+    }
+
+    #[test]
+    fn test_order() {
+        let first = VerseRef::new(
+            BibleEnum::Genesis,
+            2u8.try_into().unwrap(),
+            1u8.try_into().unwrap(),
+        );
+        let second = VerseRef::new(
+            BibleEnum::Exodus,
+            1u8.try_into().unwrap(),
+            1u8.try_into().unwrap(),
+        );
+
+        assert!(first < second);
+        assert!(first.to_packed() < second.to_packed());
+    }
+
+    #[test]
+    fn test_packed() {
+        let verse_ref = VerseRef::new(
+            BibleEnum::John,
+            3u8.try_into().unwrap(),
+            16u8.try_into().unwrap(),
+        );
+
+        assert_eq!(verse_ref.to_packed(), 43_003_016);
+        assert_eq!(VerseRef::from_packed(43_003_016).unwrap(), verse_ref);
+        assert_eq!(
+            VerseRef::from_packed(43_151_001),
+            Err(PackedVerseError(43_151_001))
+        );
+        assert!(VerseRef::from_packed(1_001).is_err());
+        assert!(VerseRef::from_packed(83_001_001).is_err());
+    }
+}
@@ -0,0 +1,652 @@
+use std::collections::BTreeMap;
+
+use book::book_components::{chapter_number::ChapterNumber, verse::Verse};
+use lazy_static::lazy_static;
+use serde::{Deserialize, Serialize};
+use strum::{EnumIter, IntoEnumIterator};
+
+use crate::{bible_enum::BibleEnum, tables, verse_ref::VerseRef};
+
+/// The numbering traditions of chapters and verses
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    Default,
+    EnumIter,
+    Serialize,
+    Deserialize,
+)]
+#[serde(rename_all = "kebab-case")]
+pub enum Versification {
+    /// The English tradition of the King James Version, used by the embedded Bible
+    #[default]
+    Kjv,
+    /// The Hebrew (Masoretic) tradition
+    Hebrew,
+    /// The Greek (Septuagint) tradition of the Psalms, shared by the Latin Vulgate
+    Septuagint,
+}
+
+#[derive(Debug, thiserror::Error, PartialEq)]
+pub enum VersificationError {
+    #[error("This versification is unknown: {0}")]
+    Unknown(String),
+    #[error("The mapped verse is out of range")]
+    OutOfRange,
+}
+
+impl Versification {
+    /// The identifier of the versification in the registry
+    pub fn id(&self) -> &'static str {
+        match self {
+            Versification::Kjv => "kjv",
+            Versification::Hebrew => "hebrew",
+            Versification::Septuagint => "septuagint",
+        }
+    }
+
+    /// Convert a verse reference of this versification to another one
+    pub fn map_to(
+        &self,
+        target: Versification,
+        verse: VerseRef,
+    ) -> Result<VerseRef, VersificationError> {
+        VERSIFICATIONS.map(self.id(), target.id(), verse)
+    }
+
+    /// The verse count of every chapter of a book in this versification, from
+    /// the verses of the embedded Bible mapped to it
+    pub fn verse_counts(&self, book: BibleEnum) -> Vec<u8> {
+        let kjv_counts: &[u8] = tables::verse_counts(book);
+        if *self == Versification::Kjv {
+            return kjv_counts.to_vec();
+        }
+
+        let mut counts: Vec<u8> = Vec::new();
+
+        for (chapter, count) in (1u16..).zip(kjv_counts) {
+            for verse in 1..=*count as u16 {
+                let Ok(mapped) = make_verse_ref(book, chapter, verse)
+                    .and_then(|verse_ref| Versification::Kjv.map_to(*self, verse_ref))
+                else {
+                    continue;
+                };
+                let index = u8::from(mapped.chapter) as usize - 1;
+
+                if counts.len() <= index {
+                    counts.resize(index + 1, 0);
+                }
+                counts[index] = counts[index].max(u8::from(mapped.verse));
+            }
+        }
+
+        counts
+    }
+
+    fn mappings(&self) -> Vec<VerseMapping> {
+        match self {
+            Versification::Kjv => Vec::new(),
+            Versification::Hebrew => hebrew_mappings(),
+            Versification::Septuagint => septuagint_mappings(),
+        }
+    }
+}
+
+impl std::str::FromStr for Versification {
+    type Err = VersificationError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "kjv" | "english" => Ok(Versification::Kjv),
+            "hebrew" | "masoretic" | "mt" => Ok(Versification::Hebrew),
+            "septuagint" | "lxx" | "vulgate" => Ok(Versification::Septuagint),
+            _ => Err(VersificationError::Unknown(s.to_string())),
+        }
+    }
+}
+
+/// A span of verses of a KJV chapter and where it starts in another versification
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct VerseMapping {
+    pub book: BibleEnum,
+    pub chapter: u8,
+    pub first_verse: u8,
+    /// The last verse of the span, `u8::MAX` for the whole chapter
+    pub last_verse: u8,
+    pub mapped_chapter: u8,
+    pub mapped_first_verse: u8,
+}
+
+impl VerseMapping {
+    pub const fn new(
+        book: BibleEnum,
+        (chapter, first_verse, last_verse): (u8, u8, u8),
+        (mapped_chapter, mapped_first_verse): (u8, u8),
+    ) -> VerseMapping {
+        VerseMapping {
+            book,
+            chapter,
+            first_verse,
+            last_verse,
+            mapped_chapter,
+            mapped_first_verse,
+        }
+    }
+
+    /// A whole chapter moved or shifted, the verses before the first mapped
+    /// verse being a title
+    pub const fn whole_chapter(
+        book: BibleEnum,
+        chapter: u8,
+        (mapped_chapter, mapped_first_verse): (u8, u8),
+    ) -> VerseMapping {
+        VerseMapping::new(
+            book,
+            (chapter, 1, u8::MAX),
+            (mapped_chapter, mapped_first_verse),
+        )
+    }
+
+    fn is_whole_chapter(&self) -> bool {
+        self.first_verse == 1 && self.last_verse == u8::MAX
+    }
+
+    /// Where a KJV verse is in the other versification
+    fn forward(&self, chapter: u8, verse: u8) -> Option<(u16, u16)> {
+        if chapter == self.chapter && (self.first_verse..=self.last_verse).contains(&verse) {
+            Some((
+                self.mapped_chapter as u16,
+                self.mapped_first_verse as u16 + (verse - self.first_verse) as u16,
+            ))
+        } else {
+            None
+        }
+    }
+
+    /// Where a verse of the other versification is in the KJV
+    fn backward(&self, chapter: u8, verse: u8) -> Option<(u16, u16)> {
+        if chapter != self.mapped_chapter {
+            return None;
+        }
+
+        let length = (self.last_verse - self.first_verse) as u16;
+
+        if verse >= self.mapped_first_verse && ((verse - self.mapped_first_verse) as u16) <= length
+        {
+            Some((
+                self.chapter as u16,
+                self.first_verse as u16 + (verse - self.mapped_first_verse) as u16,
+            ))
+        } else if self.is_whole_chapter() && verse < self.mapped_first_verse {
+            // The title of the chapter is part of the first verse
+            Some((self.chapter as u16, 1))
+        } else {
+            None
+        }
+    }
+}
+
+/// A versification described by its differences with the KJV
+#[derive(Debug, PartialEq, Clone)]
+pub struct VersificationScheme {
+    pub name: String,
+    pub mappings: Vec<VerseMapping>,
+}
+
+impl VersificationScheme {
+    pub fn new(name: &str, mappings: Vec<VerseMapping>) -> VersificationScheme {
+        VersificationScheme {
+            name: name.to_string(),
+            mappings,
+        }
+    }
+
+    /// Convert a KJV verse to this versification
+    pub fn from_kjv(&self, verse: VerseRef) -> Result<VerseRef, VersificationError> {
+        let (chapter, verse_number) = (u8::from(verse.chapter), u8::from(verse.verse));
+
+        match self
+            .mappings
+            .iter()
+            .filter(|mapping| mapping.book == verse.book)
+            .find_map(|mapping| mapping.forward(chapter, verse_number))
+        {
+            Some((chapter, verse_number)) => make_verse_ref(verse.book, chapter, verse_number),
+            None => Ok(verse),
+        }
+    }
+
+    /// Convert a verse of this versification to the KJV
+    pub fn to_kjv(&self, verse: VerseRef) -> Result<VerseRef, VersificationError> {
+        let (chapter, verse_number) = (u8::from(verse.chapter), u8::from(verse.verse));
+
+        match self
+            .mappings
+            .iter()
+            .filter(|mapping| mapping.book == verse.book)
+            .find_map(|mapping| mapping.backward(chapter, verse_number))
+        {
+            Some((chapter, verse_number)) => make_verse_ref(verse.book, chapter, verse_number),
+            None => Ok(verse),
+        }
+    }
+}
+
+/// The known versifications, by identifier
+#[derive(Debug, Clone)]
+pub struct VersificationRegistry {
+    schemes: BTreeMap<String, VersificationScheme>,
+}
+
+impl Default for VersificationRegistry {
+    fn default() -> Self {
+        let mut registry = VersificationRegistry {
+            schemes: BTreeMap::new(),
+        };
+
+        for versification in Versification::iter() {
+            registry.register(
+                versification.id(),
+                VersificationScheme::new(versification.id(), versification.mappings()),
+            );
+        }
+
+        registry
+    }
+}
+
+impl VersificationRegistry {
+    pub fn new() -> VersificationRegistry {
+        Default::default()
+    }
+
+    /// Add or replace a versification
+    pub fn register(&mut self, id: &str, scheme: VersificationScheme) {
+        self.schemes.insert(id.to_lowercase(), scheme);
+    }
+
+    pub fn get(&self, id: &str) -> Result<&VersificationScheme, VersificationError> {
+        self.schemes
+            .get(&id.to_lowercase())
+            .ok_or_else(|| VersificationError::Unknown(id.to_string()))
+    }
+
+    pub fn ids(&self) -> impl Iterator<Item = &str> {
+        self.schemes.keys().map(String::as_str)
+    }
+
+    /// Convert a verse from a versification to another, going through the KJV
+    pub fn map(
+        &self,
+        from: &str,
+        to: &str,
+        verse: VerseRef,
+    ) -> Result<VerseRef, VersificationError> {
+        let kjv_verse = self.get(from)?.to_kjv(verse)?;
+        self.get(to)?.from_kjv(kjv_verse)
+    }
+}
+
+lazy_static! {
+    pub static ref VERSIFICATIONS: VersificationRegistry = VersificationRegistry::new();
+}
+
+fn make_verse_ref(
+    book: BibleEnum,
+    chapter: u16,
+    verse: u16,
+) -> Result<VerseRef, VersificationError> {
+    let chapter: ChapterNumber = u8::try_from(chapter)
+        .ok()
+        .and_then(|chapter| ChapterNumber::try_from(chapter).ok())
+        .ok_or(VersificationError::OutOfRange)?;
+    let verse: Verse = u8::try_from(verse)
+        .ok()
+        .and_then(|verse| Verse::try_from(verse).ok())
+        .ok_or(VersificationError::OutOfRange)?;
+
+    Ok(VerseRef::new(book, chapter, verse))
+}
+
+/// The Psalms whose title is counted as one verse in the Hebrew text
+const PSALMS_ONE_VERSE_TITLE: &[u8] = &[
+    3, 4, 5, 6, 7, 8, 9, 12, 18, 19, 20, 21, 22, 30, 31, 34, 36, 38, 39, 40, 41, 42, 44, 45, 46,
+    47, 48, 49, 53, 55, 56, 57, 58, 59, 61, 62, 63, 64, 65, 67, 68, 69, 70, 75, 76, 77, 80, 81, 83,
+    84, 85, 88, 89, 92, 102, 108, 140, 142,
+];
+
+/// The Psalms whose title is counted as two verses in the Hebrew text
+const PSALMS_TWO_VERSES_TITLE: &[u8] = &[51, 52, 54, 60];
+
+fn psalm_title_length(psalm: u8) -> u8 {
+    if PSALMS_ONE_VERSE_TITLE.contains(&psalm) {
+        1
+    } else if PSALMS_TWO_VERSES_TITLE.contains(&psalm) {
+        2
+    } else {
+        0
+    }
+}
+
+const HEBREW_CHAPTER_MAPPINGS: &[VerseMapping] = &[
+    VerseMapping::new(BibleEnum::Genesis, (31, 55, 55), (32, 1)),
+    VerseMapping::new(BibleEnum::Genesis, (32, 1, 32), (32, 2)),
+    VerseMapping::new(BibleEnum::Exodus, (8, 1, 4), (7, 26)),
+    VerseMapping::new(BibleEnum::Exodus, (8, 5, 32), (8, 1)),
+    VerseMapping::new(BibleEnum::Exodus, (22, 1, 1), (21, 37)),
+    VerseMapping::new(BibleEnum::Exodus, (22, 2, 31), (22, 1)),
+    VerseMapping::new(BibleEnum::Leviticus, (6, 1, 7), (5, 20)),
+    VerseMapping::new(BibleEnum::Leviticus, (6, 8, 30), (6, 1)),
+    VerseMapping::new(BibleEnum::Numbers, (16, 36, 50), (17, 1)),
+    VerseMapping::new(BibleEnum::Numbers, (17, 1, 13), (17, 16)),
+    VerseMapping::new(BibleEnum::Numbers, (29, 40, 40), (30, 1)),
+    VerseMapping::new(BibleEnum::Numbers, (30, 1, 16), (30, 2)),
+    VerseMapping::new(BibleEnum::Deuteronomy, (12, 32, 32), (13, 1)),
+    VerseMapping::new(BibleEnum::Deuteronomy, (13, 1, 18), (13, 2)),
+    VerseMapping::new(BibleEnum::Deuteronomy, (22, 30, 30), (23, 1)),
+    VerseMapping::new(BibleEnum::Deuteronomy, (23, 1, 25), (23, 2)),
+    VerseMapping::new(BibleEnum::Deuteronomy, (29, 1, 1), (28, 69)),
+    VerseMapping::new(BibleEnum::Deuteronomy, (29, 2, 29), (29, 1)),
+    VerseMapping::new(BibleEnum::FirstSamuel, (21, 1, 15), (21, 2)),
+    VerseMapping::new(BibleEnum::FirstSamuel, (23, 29, 29), (24, 1)),
+    VerseMapping::new(BibleEnum::FirstSamuel, (24, 1, 22), (24, 2)),
+    VerseMapping::new(BibleEnum::SecondSamuel, (18, 33, 33), (19, 1)),
+    VerseMapping::new(BibleEnum::SecondSamuel, (19, 1, 43), (19, 2)),
+    VerseMapping::new(BibleEnum::FirstKings, (4, 21, 34), (5, 1)),
+    VerseMapping::new(BibleEnum::FirstKings, (5, 1, 18), (5, 15)),
+    VerseMapping::new(BibleEnum::SecondKings, (11, 21, 21), (12, 1)),
+    VerseMapping::new(BibleEnum::SecondKings, (12, 1, 21), (12, 2)),
+    VerseMapping::new(BibleEnum::FirstChronicles, (6, 1, 15), (5, 27)),
+    VerseMapping::new(BibleEnum::FirstChronicles, (6, 16, 81), (6, 1)),
+    VerseMapping::new(BibleEnum::SecondChronicles, (2, 1, 1), (1, 18)),
+    VerseMapping::new(BibleEnum::SecondChronicles, (2, 2, 18), (2, 1)),
+    VerseMapping::new(BibleEnum::SecondChronicles, (14, 1, 1), (13, 23)),
+    VerseMapping::new(BibleEnum::SecondChronicles, (14, 2, 15), (14, 1)),
+    VerseMapping::new(BibleEnum::Nehemiah, (4, 1, 6), (3, 33)),
+    VerseMapping::new(BibleEnum::Nehemiah, (4, 7, 23), (4, 1)),
+    VerseMapping::new(BibleEnum::Nehemiah, (9, 38, 38), (10, 1)),
+    VerseMapping::new(BibleEnum::Nehemiah, (10, 1, 39), (10, 2)),
+    VerseMapping::new(BibleEnum::Job, (41, 1, 8), (40, 25)),
+    VerseMapping::new(BibleEnum::Job, (41, 9, 34), (41, 1)),
+    VerseMapping::new(BibleEnum::Ecclesiastes, (5, 1, 1), (4, 17)),
+    VerseMapping::new(BibleEnum::Ecclesiastes, (5, 2, 20), (5, 1)),
+    VerseMapping::new(BibleEnum::SongOfSolomon, (6, 13, 13), (7, 1)),
+    VerseMapping::new(BibleEnum::SongOfSolomon, (7, 1, 13), (7, 2)),
+    VerseMapping::new(BibleEnum::Isaiah, (9, 1, 1), (8, 23)),
+    VerseMapping::new(BibleEnum::Isaiah, (9, 2, 21), (9, 1)),
+    // The Hebrew 63:19 holds both 63:19 and 64:1, it goes back to 63:19
+    VerseMapping::new(BibleEnum::Isaiah, (63, 19, 19), (63, 19)),
+    VerseMapping::new(BibleEnum::Isaiah, (64, 1, 1), (63, 19)),
+    VerseMapping::new(BibleEnum::Isaiah, (64, 2, 12), (64, 1)),
+    VerseMapping::new(BibleEnum::Jeremiah, (9, 1, 1), (8, 23)),
+    VerseMapping::new(BibleEnum::Jeremiah, (9, 2, 26), (9, 1)),
+    VerseMapping::new(BibleEnum::Ezekiel, (20, 45, 49), (21, 1)),
+    VerseMapping::new(BibleEnum::Ezekiel, (21, 1, 32), (21, 6)),
+    VerseMapping::new(BibleEnum::Daniel, (4, 1, 3), (3, 31)),
+    VerseMapping::new(BibleEnum::Daniel, (4, 4, 37), (4, 1)),
+    VerseMapping::new(BibleEnum::Daniel, (5, 31, 31), (6, 1)),
+    VerseMapping::new(BibleEnum::Daniel, (6, 1, 28), (6, 2)),
+    VerseMapping::new(BibleEnum::Hosea, (1, 10, 11), (2, 1)),
+    VerseMapping::new(BibleEnum::Hosea, (2, 1, 23), (2, 3)),
+    VerseMapping::new(BibleEnum::Hosea, (11, 12, 12), (12, 1)),
+    VerseMapping::new(BibleEnum::Hosea, (12, 1, 14), (12, 2)),
+    VerseMapping::new(BibleEnum::Hosea, (13, 16, 16), (14, 1)),
+    VerseMapping::new(BibleEnum::Hosea, (14, 1, 9), (14, 2)),
+    VerseMapping::new(BibleEnum::Joel, (2, 28, 32), (3, 1)),
+    VerseMapping::new(BibleEnum::Joel, (3, 1, 21), (4, 1)),
+    VerseMapping::new(BibleEnum::Jonah, (1, 17, 17), (2, 1)),
+    VerseMapping::new(BibleEnum::Jonah, (2, 1, 10), (2, 2)),
+    VerseMapping::new(BibleEnum::Micah, (5, 1, 1), (4, 14)),
+    VerseMapping::new(BibleEnum::Micah, (5, 2, 15), (5, 1)),
+    VerseMapping::new(BibleEnum::Nahum, (1, 15, 15), (2, 1)),
+    VerseMapping::new(BibleEnum::Nahum, (2, 1, 13), (2, 2)),
+    VerseMapping::new(BibleEnum::Zechariah, (1, 18, 21), (2, 1)),
+    VerseMapping::new(BibleEnum::Zechariah, (2, 1, 13), (2, 5)),
+    VerseMapping::new(BibleEnum::Malachi, (4, 1, 6), (3, 19)),
+];
+
+fn hebrew_mappings() -> Vec<VerseMapping> {
+    let mut mappings: Vec<VerseMapping> = HEBREW_CHAPTER_MAPPINGS.to_vec();
+
+    for psalm in 1u8..=150 {
+        let title_length = psalm_title_length(psalm);
+
+        if title_length > 0 {
+            mappings.push(VerseMapping::whole_chapter(
+                BibleEnum::Psalms,
+                psalm,
+                (psalm, 1 + title_length),
+            ));
+        }
+    }
+
+    mappings
+}
+
+fn septuagint_mappings() -> Vec<VerseMapping> {
+    let mut mappings: Vec<VerseMapping> = vec![
+        // Psalms 9 and 10 are one Psalm
+        VerseMapping::new(BibleEnum::Psalms, (9, 1, 20), (9, 2)),
+        VerseMapping::new(BibleEnum::Psalms, (10, 1, 18), (9, 22)),
+        // Psalms 114 and 115 are one Psalm
+        VerseMapping::new(BibleEnum::Psalms, (114, 1, 8), (113, 1)),
+        VerseMapping::new(BibleEnum::Psalms, (115, 1, 18), (113, 9)),
+        // Psalm 116 is two Psalms
+        VerseMapping::new(BibleEnum::Psalms, (116, 1, 9), (114, 1)),
+        VerseMapping::new(BibleEnum::Psalms, (116, 10, 19), (115, 1)),
+        // Psalm 147 is two Psalms
+        VerseMapping::new(BibleEnum::Psalms, (147, 1, 11), (146, 1)),
+        VerseMapping::new(BibleEnum::Psalms, (147, 12, 20), (147, 1)),
+    ];
+
+    for psalm in (1u8..=8).chain(11..=113).chain(117..=146) {
+        let mapped_psalm = if psalm > 8 { psalm - 1 } else { psalm };
+        let title_length = psalm_title_length(psalm);
+
+        if mapped_psalm != psalm || title_length > 0 {
+            mappings.push(VerseMapping::whole_chapter(
+                BibleEnum::Psalms,
+                psalm,
+                (mapped_psalm, 1 + title_length),
+            ));
+        }
+    }
+
+    mappings
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use super::*;
+    use crate::BIBLE;
+
+    fn verse_ref(book: BibleEnum, chapter: u8, verse: u8) -> VerseRef {
+        VerseRef::new(book, chapter.try_into().unwrap(), verse.try_into().unwrap())
+    }
+
+    #[test]
+    fn test_malachi() {
+        let kjv = verse_ref(BibleEnum::Malachi, 4, 5);
+        let hebrew = verse_ref(BibleEnum::Malachi, 3, 23);
+
+        assert_eq!(
+            Versification::Kjv
+                .map_to(Versification::Hebrew, kjv)
+                .unwrap(),
+            hebrew
+        );
+        assert_eq!(
+            Versification::Hebrew
+                .map_to(Versification::Kjv, hebrew)
+                .unwrap(),
+            kjv
+        );
+    }
+
+    #[test]
+    fn test_joel() {
+        assert_eq!(
+            Versification::Hebrew
+                .map_to(Versification::Kjv, verse_ref(BibleEnum::Joel, 3, 1))
+                .unwrap(),
+            verse_ref(BibleEnum::Joel, 2, 28)
+        );
+        assert_eq!(
+            Versification::Hebrew
+                .map_to(Versification::Kjv, verse_ref(BibleEnum::Joel, 4, 21))
+                .unwrap(),
+            verse_ref(BibleEnum::Joel, 3, 21)
+        );
+    }
+
+    #[test]
+    fn test_psalm_titles() {
+        assert_eq!(
+            Versification::Kjv
+                .map_to(Versification::Hebrew, verse_ref(BibleEnum::Psalms, 51, 1))
+                .unwrap(),
+            verse_ref(BibleEnum::Psalms, 51, 3)
+        );
+
+        // The title lands on the first verse
+        assert_eq!(
+            Versification::Hebrew
+                .map_to(Versification::Kjv, verse_ref(BibleEnum::Psalms, 51, 2))
+                .unwrap(),
+            verse_ref(BibleEnum::Psalms, 51, 1)
+        );
+    }
+
+    #[test]
+    fn test_septuagint_psalms() {
+        assert_eq!(
+            Versification::Kjv
+                .map_to(
+                    Versification::Septuagint,
+                    verse_ref(BibleEnum::Psalms, 23, 1)
+                )
+                .unwrap(),
+            verse_ref(BibleEnum::Psalms, 22, 1)
+        );
+        assert_eq!(
+            Versification::Kjv
+                .map_to(
+                    Versification::Septuagint,
+                    verse_ref(BibleEnum::Psalms, 51, 1)
+                )
+                .unwrap(),
+            verse_ref(BibleEnum::Psalms, 50, 3)
+        );
+        assert_eq!(
+            Versification::Septuagint
+                .map_to(Versification::Kjv, verse_ref(BibleEnum::Psalms, 9, 22))
+                .unwrap(),
+            verse_ref(BibleEnum::Psalms, 10, 1)
+        );
+        assert_eq!(
+            Versification::Hebrew
+                .map_to(
+                    Versification::Septuagint,
+                    verse_ref(BibleEnum::Psalms, 117, 1)
+                )
+                .unwrap(),
+            verse_ref(BibleEnum::Psalms, 116, 1)
+        );
+    }
+
+    #[test]
+    fn test_round_trip_every_kjv_verse() {
+        for versification in Versification::iter() {
+            for (book_enum, book) in BIBLE.range(BibleEnum::Genesis..=BibleEnum::Malachi) {
+                for chapter in &book.chapters {
+                    for verse in 1..=u8::from(chapter.get_max_verse()) {
+                        let kjv = VerseRef::new(
+                            *book_enum,
+                            *chapter.get_chapter_number(),
+                            verse.try_into().unwrap(),
+                        );
+                        let mapped = Versification::Kjv.map_to(versification, kjv).unwrap();
+
+                        // Isaiah 64:1 is the second half of the Hebrew 63:19
+                        if kjv == verse_ref(BibleEnum::Isaiah, 64, 1) {
+                            continue;
+                        }
+
+                        assert_eq!(versification.map_to(Versification::Kjv, mapped), Ok(kjv));
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_mappings_fit_kjv_chapters() {
+        for mapping in hebrew_mappings().iter().chain(&septuagint_mappings()) {
+            if mapping.last_verse != u8::MAX {
+                let chapter = BIBLE[mapping.book]
+                    .chapters
+                    .get(mapping.chapter.try_into().unwrap())
+                    .unwrap();
+
+                assert!(mapping.last_verse <= u8::from(chapter.get_max_verse()));
+            }
+        }
+    }
+
+    #[test]
+    fn test_registry() {
+        let mut registry = VersificationRegistry::new();
+        registry.register(
+            "custom",
+            VersificationScheme::new(
+                "Custom",
+                vec![VerseMapping::new(BibleEnum::Jude, (1, 1, 25), (1, 2))],
+            ),
+        );
+
+        assert_eq!(
+            registry
+                .map("custom", "kjv", verse_ref(BibleEnum::Jude, 1, 3))
+                .unwrap(),
+            verse_ref(BibleEnum::Jude, 1, 2)
+        );
+        assert_eq!(
+            registry.get("unknown").unwrap_err(),
+            VersificationError::Unknown("unknown".to_string())
+        );
+        assert_eq!(
+            Versification::from_str("LXX").unwrap(),
+            Versification::Septuagint
+        );
+    }
+
+    #[test]
+    fn test_verse_counts() {
+        let hebrew_malachi = Versification::Hebrew.verse_counts(BibleEnum::Malachi);
+        assert_eq!(hebrew_malachi, vec![14, 17, 24]);
+
+        let hebrew_joel = Versification::Hebrew.verse_counts(BibleEnum::Joel);
+        assert_eq!(hebrew_joel, vec![20, 27, 5, 21]);
+
+        let hebrew_psalms = Versification::Hebrew.verse_counts(BibleEnum::Psalms);
+        assert_eq!(hebrew_psalms[2], 9);
+        assert_eq!(hebrew_psalms[50], 21);
+
+        let septuagint_psalms = Versification::Septuagint.verse_counts(BibleEnum::Psalms);
+        assert_eq!(septuagint_psalms.len(), 150);
+        assert_eq!(septuagint_psalms[8], 39);
+
+        assert_eq!(
+            Versification::Kjv.verse_counts(BibleEnum::AdditionsToEsther)[0],
+            0
+        );
+    }
+}
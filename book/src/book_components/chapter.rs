@@ -6,11 +6,26 @@ use super::{chapter_number::ChapterNumber, verse::Verse};
 pub struct Chapter {
     number: ChapterNumber,
     max_verse: Verse,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    text: Vec<String>,
 }
 
 impl Chapter {
     pub fn new(number: ChapterNumber, max_verse: Verse) -> Chapter {
-        Chapter { number, max_verse }
+        Chapter {
+            number,
+            max_verse,
+            text: Vec::new(),
+        }
+    }
+
+    /// Create a chapter with the text of its verses, the first element being verse 1
+    pub fn with_text(number: ChapterNumber, max_verse: Verse, text: Vec<String>) -> Chapter {
+        Chapter {
+            number,
+            max_verse,
+            text,
+        }
     }
 
     pub fn get_chapter_number(&self) -> &ChapterNumber {
@@ -20,4 +35,48 @@ impl Chapter {
     pub fn get_max_verse(&self) -> &Verse {
         &self.max_verse
     }
+
+    /// The text of every verse of the chapter, empty if no text is loaded
+    pub fn get_text(&self) -> &[String] {
+        &self.text
+    }
+
+    pub fn get_verse_text(&self, verse: Verse) -> Option<&str> {
+        self.text
+            .get(u8::from(verse) as usize - 1)
+            .map(String::as_str)
+    }
+
+    pub fn has_text(&self) -> bool {
+        !self.text.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_verse_text() {
+        let chapter = Chapter::with_text(
+            1u8.try_into().unwrap(),
+            2u8.try_into().unwrap(),
+            vec!["First verse".to_string(), "Second verse".to_string()],
+        );
+
+        assert!(chapter.has_text());
+        assert_eq!(
+            chapter.get_verse_text(2u8.try_into().unwrap()),
+            Some("Second verse")
+        );
+        assert_eq!(chapter.get_verse_text(3u8.try_into().unwrap()), None);
+    }
+
+    #[test]
+    fn test_without_text() {
+        let chapter = Chapter::new(1u8.try_into().unwrap(), 2u8.try_into().unwrap());
+
+        assert!(!chapter.has_text());
+        assert_eq!(chapter.get_verse_text(1u8.try_into().unwrap()), None);
+    }
 }
@@ -1,12 +1,18 @@
 use std::{
     collections::BTreeMap,
-    ops::{Index, IndexMut},
+    ops::{Index, IndexMut, RangeBounds},
 };
 
 use serde::{Deserialize, Serialize};
 
 use super::{chapter::Chapter, chapter_number::ChapterNumber};
 
+#[derive(Debug, thiserror::Error, PartialEq)]
+pub enum ChapterStoreError {
+    #[error("Chapter {0} not found in book")]
+    ChapterNotFound(u8),
+}
+
 #[derive(Debug, PartialEq, Clone, Default, Serialize, Deserialize)]
 pub struct ChapterStore {
     store: BTreeMap<ChapterNumber, Chapter>,
@@ -31,19 +37,74 @@ impl ChapterStore {
         self.store.get_mut(&chapter_number)
     }
 
-    pub fn range(
+    pub fn try_get(&self, chapter_number: ChapterNumber) -> Result<&Chapter, ChapterStoreError> {
+        self.get(chapter_number)
+            .ok_or(ChapterStoreError::ChapterNotFound(chapter_number.into()))
+    }
+
+    pub fn try_get_mut(
+        &mut self,
+        chapter_number: ChapterNumber,
+    ) -> Result<&mut Chapter, ChapterStoreError> {
+        self.get_mut(chapter_number)
+            .ok_or(ChapterStoreError::ChapterNotFound(chapter_number.into()))
+    }
+
+    pub fn chapter_count(&self) -> usize {
+        self.store.len()
+    }
+
+    /// The number of verses of every chapter
+    pub fn verse_count(&self) -> u32 {
+        self.store
+            .values()
+            .map(|chapter| u8::from(chapter.get_max_verse()) as u32)
+            .sum()
+    }
+
+    /// The chapter with the most verses, the first one if several have as many
+    pub fn longest_chapter(&self) -> Option<&Chapter> {
+        self.store
+            .values()
+            .rev()
+            .max_by_key(|chapter| chapter.get_max_verse())
+    }
+
+    /// The chapter with the fewest verses, the first one if several have as few
+    pub fn shortest_chapter(&self) -> Option<&Chapter> {
+        self.store
+            .values()
+            .min_by_key(|chapter| chapter.get_max_verse())
+    }
+
+    /// The chapters whose numbers are in a range, like `first..=last` or
+    /// `(Bound::Excluded(current), Bound::Unbounded)`
+    pub fn range<R: RangeBounds<ChapterNumber>>(
         &self,
-        range: core::ops::Range<ChapterNumber>,
-    ) -> std::collections::btree_map::Range<ChapterNumber, Chapter> {
+        range: R,
+    ) -> std::collections::btree_map::Range<'_, ChapterNumber, Chapter> {
         self.store.range(range)
     }
+
+    /// The chapter with the lowest number, which is not always 1
+    pub fn first(&self) -> Option<&Chapter> {
+        self.store.values().next()
+    }
+
+    /// The chapter with the highest number
+    pub fn last(&self) -> Option<&Chapter> {
+        self.store.values().next_back()
+    }
 }
 
+/// Panics if the chapter is not in the store, [`ChapterStore::try_get`] being
+/// the fallible access
 impl Index<ChapterNumber> for ChapterStore {
     type Output = Chapter;
 
     fn index(&self, index: ChapterNumber) -> &Self::Output {
-        self.store.get(&index).unwrap()
+        self.try_get(index)
+            .unwrap_or_else(|error| panic!("{error}"))
     }
 }
 
@@ -57,7 +118,8 @@ impl Index<&ChapterNumber> for ChapterStore {
 
 impl IndexMut<ChapterNumber> for ChapterStore {
     fn index_mut(&mut self, index: ChapterNumber) -> &mut Self::Output {
-        self.store.get_mut(&index).unwrap()
+        self.try_get_mut(index)
+            .unwrap_or_else(|error| panic!("{error}"))
     }
 }
 
@@ -95,11 +157,79 @@ mod tests {
             store.add_chapter(chapter);
         }
 
-        let mut num: u8 = 1;
-        for chapter in store.into_iter() {
+        for (num, chapter) in (1u8..).zip(&store) {
             let chapter_num: &ChapterNumber = chapter.get_chapter_number();
             assert_eq!(u8::from(chapter_num), num);
-            num += 1;
         }
     }
+
+    #[test]
+    fn test_statistics() {
+        let mut store: ChapterStore = ChapterStore::new();
+
+        for (number, verses) in [(1u8, 12u8), (2, 30), (3, 5), (4, 30), (5, 5)] {
+            store.add_chapter(Chapter::new(
+                number.try_into().unwrap(),
+                verses.try_into().unwrap(),
+            ));
+        }
+
+        assert_eq!(store.chapter_count(), 5);
+        assert_eq!(store.verse_count(), 82);
+        assert_eq!(
+            u8::from(store.longest_chapter().unwrap().get_chapter_number()),
+            2
+        );
+        assert_eq!(
+            u8::from(store.shortest_chapter().unwrap().get_chapter_number()),
+            3
+        );
+        assert!(ChapterStore::new().longest_chapter().is_none());
+    }
+
+    #[test]
+    fn test_try_get() {
+        let mut store: ChapterStore = ChapterStore::new();
+        store.add_chapter(Chapter::new(
+            2u8.try_into().unwrap(),
+            Verse::try_from(10u8).unwrap(),
+        ));
+
+        assert!(store.try_get(2u8.try_into().unwrap()).is_ok());
+        assert_eq!(
+            store.try_get(1u8.try_into().unwrap()).unwrap_err(),
+            ChapterStoreError::ChapterNotFound(1)
+        );
+    }
+
+    #[test]
+    fn test_range() {
+        let mut store: ChapterStore = ChapterStore::new();
+
+        for number in 1u8..=5 {
+            store.add_chapter(Chapter::new(
+                number.try_into().unwrap(),
+                Verse::try_from(10u8).unwrap(),
+            ));
+        }
+
+        let numbers = |range: std::collections::btree_map::Range<'_, ChapterNumber, Chapter>| {
+            range
+                .map(|(number, _)| u8::from(number))
+                .collect::<Vec<u8>>()
+        };
+        let two: ChapterNumber = 2u8.try_into().unwrap();
+        let four: ChapterNumber = 4u8.try_into().unwrap();
+
+        assert_eq!(numbers(store.range(two..four)), vec![2, 3]);
+        assert_eq!(numbers(store.range(two..=four)), vec![2, 3, 4]);
+        assert_eq!(numbers(store.range(four..)), vec![4, 5]);
+        assert_eq!(numbers(store.range(..two)), vec![1]);
+        assert_eq!(
+            numbers(store.range((std::ops::Bound::Excluded(two), std::ops::Bound::Unbounded))),
+            vec![3, 4, 5]
+        );
+        assert_eq!(u8::from(store.first().unwrap().get_chapter_number()), 1);
+        assert_eq!(u8::from(store.last().unwrap().get_chapter_number()), 5);
+    }
 }
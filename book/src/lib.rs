@@ -8,3 +8,13 @@ pub struct Book {
     pub name: BookName,
     pub chapters: ChapterStore,
 }
+
+impl Book {
+    pub fn chapter_count(&self) -> usize {
+        self.chapters.chapter_count()
+    }
+
+    pub fn verse_count(&self) -> u32 {
+        self.chapters.verse_count()
+    }
+}
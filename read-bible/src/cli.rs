@@ -1,3 +1,4 @@
+use bible::locale::Locale;
 use clap::CommandFactory;
 use clap::{Parser, Subcommand};
 
@@ -10,6 +11,12 @@ pub struct Cli {
     /// The path for the save file
     #[arg(short, long, default_value = "reading.ron")]
     pub path: std::path::PathBuf,
+    /// The directory of a data pack to read the Bible from
+    #[arg(long)]
+    pub pack: Option<std::path::PathBuf>,
+    /// The language of the book names, instead of the one of the configuration
+    #[arg(short, long)]
+    pub locale: Option<Locale>,
     /// Command
     #[command(subcommand)]
     pub command: Commands,
@@ -25,6 +32,20 @@ pub enum Commands {
     Next(cmd::next_previous::NextPreviousArgs),
     /// Go to the previous resource
     Previous(cmd::next_previous::NextPreviousArgs),
+    /// Count the books, chapters and verses of the Bible or of a passage
+    Info(cmd::info::InfoArgs),
+    /// Build a data pack from the USFM, USX or OSIS files of a translation
+    Import(cmd::import::ImportArgs),
+    /// List the Bible modules of a SWORD library, or write one as a data pack
+    Sword(cmd::sword::SwordArgs),
+    /// List the changes of the reading, or show where it was on a day
+    History(cmd::history::HistoryArgs),
+    /// Go back to the reading before the last change
+    Undo,
+    /// Make again the last change undone
+    Redo,
+    /// Show, import or check reading plans
+    Plan(cmd::plan::PlanArgs),
 }
 
 pub fn create_doc_man() -> std::io::Result<()> {
@@ -4,6 +4,8 @@ use serde::Serialize;
 #[derive(ValueEnum, Clone, Default, Debug, Serialize)]
 #[serde(rename_all = "kebab-case")]
 pub enum ChoiceEntity {
+    /// The Section entity, like the Law or the Gospels
+    Section,
     /// The Book entity
     Book,
     /// The Chapter entity
@@ -0,0 +1,61 @@
+use bible::locale::{Locale, LocalizedDisplay};
+use chrono::{DateTime, Local, NaiveDate, TimeZone, Utc};
+use clap::Args;
+use read::{history::History, launch_reading, reading::Reading};
+
+#[derive(Debug, Args)]
+#[command(version, about, long_about = None)]
+pub struct HistoryArgs {
+    /// Show where the reading was at the end of a day, like 2024-03-21,
+    /// instead of the changes
+    #[arg(long)]
+    on: Option<NaiveDate>,
+    /// Show only the last changes
+    #[arg(short = 'n', long)]
+    last: Option<usize>,
+}
+
+pub fn main(path: std::path::PathBuf, locale: Locale, args: &HistoryArgs) -> anyhow::Result<()> {
+    let client = launch_reading(1, path);
+
+    let reading: Reading = client.get_reading_from_file()?;
+    let history = client.get_history()?;
+    let history: &History = &history.lock().unwrap();
+
+    match args.on {
+        Some(date) => {
+            let end_of_day: DateTime<Utc> = Local
+                .from_local_datetime(&date.and_hms_nano_opt(23, 59, 59, 999_999_999).unwrap())
+                .latest()
+                .map_or_else(
+                    || date.and_time(Default::default()).and_utc(),
+                    |time| time.with_timezone(&Utc),
+                );
+
+            // A reading never changed was always where it is
+            let reading: &Reading = history.reading_at(end_of_day).unwrap_or(&reading);
+            println!("{}", reading.localized(locale));
+        }
+        None => {
+            let entries = history.entries();
+            let skipped: usize = args
+                .last
+                .map_or(0, |last| entries.len().saturating_sub(last));
+
+            for entry in &entries[skipped..] {
+                println!(
+                    "{}  {}  {} -> {}",
+                    entry
+                        .timestamp
+                        .with_timezone(&Local)
+                        .format("%Y-%m-%d %H:%M"),
+                    entry.command,
+                    entry.from.localized(locale),
+                    entry.to.localized(locale)
+                );
+            }
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,77 @@
+use std::collections::BTreeMap;
+
+use bible::{bible_enum::BibleEnum, data_pack::PackMetadata, import::Import};
+use clap::Args;
+
+#[derive(Debug, Args)]
+#[command(version, about, long_about = None)]
+pub struct ImportArgs {
+    /// An OSIS file, or a directory of USFM, USX or OSIS files
+    source: std::path::PathBuf,
+    /// The directory where the data pack is written
+    #[arg(short, long)]
+    output: std::path::PathBuf,
+    /// The name of the translation
+    #[arg(long)]
+    name: String,
+    /// The language code of the translation, like "en" or "fr"
+    #[arg(long, default_value = "en")]
+    language: String,
+    /// The abbreviation of the translation, like "KJV"
+    #[arg(long)]
+    abbreviation: Option<String>,
+    /// The license of the translation
+    #[arg(long)]
+    license: Option<String>,
+}
+
+pub fn main(args: &ImportArgs) -> anyhow::Result<()> {
+    let metadata = PackMetadata {
+        name: args.name.clone(),
+        language: args.language.clone(),
+        abbreviation: args.abbreviation.clone(),
+        license: args.license.clone(),
+    };
+    let import = if args.source.is_dir() {
+        Import::from_directory(&args.source, metadata)?
+    } else {
+        Import::from_file(&args.source, metadata)?
+    };
+
+    import.pack.save(&args.output)?;
+
+    let report = &import.report;
+    println!(
+        "{} books imported into {}",
+        report.books.len(),
+        args.output.display()
+    );
+    println!(
+        "{}",
+        report
+            .books
+            .iter()
+            .map(|book| format!("{book:?}"))
+            .collect::<Vec<String>>()
+            .join(", ")
+    );
+
+    for path in &report.skipped {
+        println!("Skipped {}", path.display());
+    }
+
+    let mut missing: BTreeMap<BibleEnum, usize> = BTreeMap::new();
+    for verse_ref in &report.missing {
+        *missing.entry(verse_ref.book).or_default() += 1;
+    }
+
+    for (book, count) in missing {
+        println!("{book:?}: {count} verses missing");
+    }
+
+    for verse_ref in &report.extra {
+        println!("{verse_ref} is not in the default versification");
+    }
+
+    Ok(())
+}
@@ -0,0 +1,120 @@
+use bible::{
+    bible_enum::BibleEnum,
+    canon::Canon,
+    locale::{Locale, LocalizedDisplay},
+    metadata::{Section, Testament},
+    passage::Passage,
+    reference::ScriptureReference,
+    statistics::{ChapterLength, Statistics},
+    Bible, BIBLE,
+};
+use book::Book;
+use clap::Args;
+use strum::IntoEnumIterator;
+
+#[derive(Debug, Args)]
+#[command(version, about, long_about = None)]
+pub struct InfoArgs {
+    /// A passage to measure, like "Genesis 1-11", instead of the whole Bible
+    reference: Option<ScriptureReference>,
+    /// A book to measure, instead of the whole Bible
+    #[arg(long, conflicts_with = "reference")]
+    book: Option<BibleEnum>,
+    /// The canon whose books are measured
+    #[arg(long)]
+    canon: Option<Canon>,
+}
+
+pub fn main(
+    pack: Option<std::path::PathBuf>,
+    locale: Locale,
+    args: &InfoArgs,
+) -> anyhow::Result<()> {
+    let pack_bible: Option<Bible> = match pack {
+        Some(pack_path) => Some(Bible::load_pack(&pack_path)?),
+        None => None,
+    };
+    let bible: &Bible = pack_bible.as_ref().unwrap_or(&BIBLE);
+
+    if let Some(reference) = &args.reference {
+        let passage = Passage::from_reference_in(reference, bible)?;
+        print_statistics(
+            &passage.localized(locale).to_string(),
+            &bible.passage_statistics(&passage),
+            locale,
+        );
+        return Ok(());
+    }
+
+    if let Some(book) = args.book {
+        print_statistics(
+            book.localized_name(&locale),
+            &bible.book_statistics(book)?,
+            locale,
+        );
+        return Ok(());
+    }
+
+    let canon = args.canon.unwrap_or_default();
+    let in_canon = |(book, _): &(&BibleEnum, &Book)| canon.contains(book);
+
+    let testaments: Vec<(Testament, Statistics)> = Testament::iter()
+        .map(|testament| {
+            (
+                testament,
+                Statistics::from_books(bible.books_in_testament(testament).filter(in_canon)),
+            )
+        })
+        .collect();
+
+    print_statistics(
+        "Bible",
+        &Statistics::from_books(
+            Testament::iter()
+                .flat_map(|testament| bible.books_in_testament(testament).filter(in_canon)),
+        ),
+        locale,
+    );
+
+    for (testament, statistics) in testaments {
+        print_statistics(
+            &testament.localized(locale).to_string(),
+            &statistics,
+            locale,
+        );
+    }
+
+    for section in Section::iter() {
+        let statistics = Statistics::from_books(bible.books_in_section(section).filter(in_canon));
+
+        if statistics.books > 0 {
+            print_statistics(&section.localized(locale).to_string(), &statistics, locale);
+        }
+    }
+
+    Ok(())
+}
+
+fn print_statistics(label: &str, statistics: &Statistics, locale: Locale) {
+    println!(
+        "{label}: {} books, {} chapters, {} verses",
+        statistics.books, statistics.chapters, statistics.verses
+    );
+
+    if let Some(longest) = statistics.longest_chapter {
+        println!("  Longest chapter: {}", chapter_length(&longest, locale));
+    }
+
+    if let Some(shortest) = statistics.shortest_chapter {
+        println!("  Shortest chapter: {}", chapter_length(&shortest, locale));
+    }
+}
+
+fn chapter_length(length: &ChapterLength, locale: Locale) -> String {
+    format!(
+        "{} {} ({} verses)",
+        length.book.localized_name(&locale),
+        u8::from(length.chapter),
+        length.verses
+    )
+}
@@ -1,4 +1,10 @@
 pub mod choice_entity;
+pub mod history;
+pub mod import;
+pub mod info;
 pub mod next_previous;
+pub mod plan;
 pub mod set;
 pub mod show;
+pub mod sword;
+pub mod undo_redo;
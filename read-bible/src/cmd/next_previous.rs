@@ -1,6 +1,7 @@
-use bible::bible_enum::BibleEnum;
+use bible::{bible_enum::BibleEnum, Bible, BIBLE};
 use clap::Args;
 use read::{
+    boundary::BoundaryPolicy,
     launch_reading,
     reading::{Reading, ReadingError},
 };
@@ -20,53 +21,136 @@ pub struct NextPreviousArgs {
     entity: ChoiceEntity,
     /// The count of entity
     #[arg(short, long, default_value = "1")]
-    count: u8,
+    count: u32,
+    /// What to do beyond the first or the last book: wrap, clamp or error,
+    /// instead of the one of the reading
+    #[arg(short, long)]
+    boundary: Option<BoundaryPolicy>,
 }
 
 impl NextPreviousArgs {
-    pub fn next(&self, reading: &mut Reading) -> Result<(), ReadingError> {
+    pub fn next(&self, reading: &mut Reading, bible: &Bible) -> Result<(), ReadingError> {
         match self.entity {
-            ChoiceEntity::Book => reading.next_book(&self.count),
-            ChoiceEntity::Chapter => reading.next_chapter(&self.count),
-            ChoiceEntity::Verse => reading.next_verse(&self.count),
+            ChoiceEntity::Section => reading.next_section_in(&self.count, bible),
+            ChoiceEntity::Book => reading.next_book_in(&self.count, bible),
+            ChoiceEntity::Chapter => reading.next_chapter_in(&self.count, bible),
+            ChoiceEntity::Verse => reading.next_verse_in(&self.count, bible),
         }
     }
 
-    pub fn previous(&self, reading: &mut Reading) -> Result<(), ReadingError> {
+    pub fn previous(&self, reading: &mut Reading, bible: &Bible) -> Result<(), ReadingError> {
         match self.entity {
-            ChoiceEntity::Book => reading.previous_book(&self.count),
-            ChoiceEntity::Chapter => reading.previous_chapter(&self.count),
-            ChoiceEntity::Verse => reading.previous_verse(&self.count),
+            ChoiceEntity::Section => reading.previous_section_in(&self.count, bible),
+            ChoiceEntity::Book => reading.previous_book_in(&self.count, bible),
+            ChoiceEntity::Chapter => reading.previous_chapter_in(&self.count, bible),
+            ChoiceEntity::Verse => reading.previous_verse_in(&self.count, bible),
         }
     }
+
+    /// The command recorded in the history, like "next 2 chapter"
+    pub fn command(&self, direction: &Direction) -> String {
+        let direction: &str = match direction {
+            Direction::Next => "next",
+            Direction::Previous => "previous",
+        };
+        let entity: &str = match self.entity {
+            ChoiceEntity::Section => "section",
+            ChoiceEntity::Book => "book",
+            ChoiceEntity::Chapter => "chapter",
+            ChoiceEntity::Verse => "verse",
+        };
+
+        format!("{} {} {}", direction, self.count, entity)
+    }
 }
 
 pub fn main(
     path: std::path::PathBuf,
+    pack: Option<std::path::PathBuf>,
+    boundary: BoundaryPolicy,
     args: &NextPreviousArgs,
     direction: Direction,
 ) -> anyhow::Result<()> {
     let client = launch_reading(1, path);
 
+    let pack_bible: Option<Bible> = match pack {
+        Some(pack_path) => Some(Bible::load_pack(&pack_path)?),
+        None => None,
+    };
+    let bible: &Bible = pack_bible.as_ref().unwrap_or(&BIBLE);
+
     let mut new_reading: Reading = Reading::new(
         BibleEnum::Genesis,
         1u8.try_into().unwrap(),
         1u8.try_into().unwrap(),
     )
     .unwrap();
+    new_reading.set_boundary_policy(boundary);
 
     if let Ok(existant_reading) = client.get_reading_from_file() {
+        // The reading keeps its policy unless another one is given
         new_reading = existant_reading;
+        if let Some(boundary) = args.boundary {
+            new_reading.set_boundary_policy(boundary);
+        }
         match direction {
-            Direction::Next => args.next(&mut new_reading).unwrap(),
-            Direction::Previous => args.previous(&mut new_reading).unwrap(),
+            Direction::Next => args.next(&mut new_reading, bible)?,
+            Direction::Previous => args.previous(&mut new_reading, bible)?,
         };
     }
 
     client
-        .set_current_reading(new_reading)
+        .change_current_reading(new_reading, args.command(&direction))
         .expect("There was a problem when we set the new reading");
     client.save_reading_in_file()?;
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_saved_policy_is_kept() {
+        let path = std::env::temp_dir().join(format!(
+            "bible_read_test_next_policy_{}.ron",
+            std::process::id()
+        ));
+
+        let mut reading = Reading::new(
+            BibleEnum::Revelation,
+            22u8.try_into().unwrap(),
+            21u8.try_into().unwrap(),
+        )
+        .unwrap();
+        reading.set_boundary_policy(BoundaryPolicy::Clamp);
+
+        let client = launch_reading(1, path.clone());
+        client.change_current_reading(reading, "set").unwrap();
+        client.save_reading_in_file().unwrap();
+
+        let args = NextPreviousArgs {
+            entity: ChoiceEntity::Verse,
+            count: 1,
+            boundary: None,
+        };
+        main(
+            path.clone(),
+            None,
+            BoundaryPolicy::Wrap,
+            &args,
+            Direction::Next,
+        )
+        .unwrap();
+
+        let reading = launch_reading(1, path.clone())
+            .get_reading_from_file()
+            .unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(*reading.current_book(), BibleEnum::Revelation);
+        assert_eq!(reading.cycles(), 0);
+        assert_eq!(reading.boundary_policy(), BoundaryPolicy::Clamp);
+    }
+}
@@ -0,0 +1,144 @@
+use bible::{
+    locale::{Locale, LocalizedDisplay},
+    Bible, BIBLE,
+};
+use chrono::{Datelike, Local, NaiveDate};
+use clap::{Args, Subcommand};
+use read::plan::{custom::PlanDefinition, BuiltinPlan, ReadingPlan};
+
+#[derive(Debug, Args)]
+#[command(version, about, long_about = None)]
+pub struct PlanArgs {
+    #[command(subcommand)]
+    command: PlanCommands,
+}
+
+#[derive(Debug, Subcommand)]
+enum PlanCommands {
+    /// List the built-in reading plans
+    List,
+    /// Show the passages to read on a day of a plan
+    Show(ShowPlanArgs),
+    /// Check a RON, TOML or CSV plan file, and write it as a RON plan file
+    Import(ImportPlanArgs),
+    /// Check the references of a RON, TOML or CSV plan file
+    Validate(ValidatePlanArgs),
+}
+
+#[derive(Debug, Args)]
+struct ShowPlanArgs {
+    /// The plan: canonical, new-testament, chronological, mcheyne,
+    /// psalms-proverbs, or the path of a plan file
+    plan: String,
+    /// The first day of the plan, the 1st of January of this year by default
+    #[arg(long)]
+    start: Option<NaiveDate>,
+    /// The date whose passages are shown, today by default
+    #[arg(long)]
+    date: Option<NaiveDate>,
+    /// The number of the day whose passages are shown, instead of a date
+    #[arg(long, conflicts_with = "date")]
+    day: Option<usize>,
+}
+
+#[derive(Debug, Args)]
+struct ImportPlanArgs {
+    /// The plan file to import
+    source: std::path::PathBuf,
+    /// The RON plan file to write
+    #[arg(short, long)]
+    output: std::path::PathBuf,
+}
+
+#[derive(Debug, Args)]
+struct ValidatePlanArgs {
+    /// The plan file to check
+    source: std::path::PathBuf,
+}
+
+pub fn main(
+    pack: Option<std::path::PathBuf>,
+    locale: Locale,
+    args: &PlanArgs,
+) -> anyhow::Result<()> {
+    let pack_bible: Option<Bible> = match pack {
+        Some(pack_path) => Some(Bible::load_pack(&pack_path)?),
+        None => None,
+    };
+    let bible: &Bible = pack_bible.as_ref().unwrap_or(&BIBLE);
+
+    match &args.command {
+        PlanCommands::List => {
+            let start: NaiveDate = Local::now().date_naive();
+
+            for plan in BuiltinPlan::ALL {
+                println!(
+                    "{}: {} days",
+                    plan,
+                    plan.build_in(start, bible)?.days().len()
+                );
+            }
+        }
+        PlanCommands::Show(args) => {
+            let today: NaiveDate = Local::now().date_naive();
+            let start: NaiveDate = args
+                .start
+                .unwrap_or_else(|| today.with_ordinal(1).unwrap_or(today));
+            let plan: ReadingPlan = match args.plan.parse::<BuiltinPlan>() {
+                Ok(builtin_plan) => builtin_plan.build_in(start, bible)?,
+                Err(_) => PlanDefinition::load(std::path::Path::new(&args.plan))?
+                    .to_plan_in(start, bible)?,
+            };
+
+            let number: Option<usize> = match args.day {
+                Some(day) => Some(day),
+                None => plan.day_number_on(args.date.unwrap_or(today)),
+            };
+
+            match number.and_then(|number| Some((number, plan.day(number)?))) {
+                Some((number, passages)) => {
+                    println!("Day {} of {}", number, plan.days().len());
+                    for passage in passages {
+                        println!("{}", passage.localized(locale));
+                    }
+                }
+                None => println!("There is no day of the plan to read"),
+            }
+        }
+        PlanCommands::Import(args) => {
+            let definition: PlanDefinition = PlanDefinition::load(&args.source)?;
+
+            if let Some(error) = definition.errors_in(bible).into_iter().next() {
+                return Err(error.into());
+            }
+
+            std::fs::write(&args.output, definition.to_ron())?;
+            println!(
+                "{} of {} days written to {}",
+                definition.name,
+                definition.days.len(),
+                args.output.display()
+            );
+        }
+        PlanCommands::Validate(args) => {
+            let definition: PlanDefinition = PlanDefinition::load(&args.source)?;
+            let errors = definition.errors_in(bible);
+
+            for error in &errors {
+                println!("{}", error);
+            }
+
+            if !errors.is_empty() {
+                anyhow::bail!("{} has {} errors", args.source.display(), errors.len());
+            }
+
+            println!(
+                "{} of {} days is valid",
+                definition.name,
+                definition.days.len()
+            );
+        }
+    }
+
+    Ok(())
+}
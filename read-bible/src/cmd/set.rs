@@ -1,6 +1,10 @@
-use bible::bible_enum::BibleEnum;
+use bible::{
+    bible_enum::BibleEnum, canon::Canon, reference::ScriptureReference, verse_ref::VerseRef,
+    versification::Versification, Bible, BIBLE,
+};
 use book::book_components::{chapter_number::ChapterNumber, verse::Verse};
 use clap::Args;
+use read::boundary::BoundaryPolicy;
 use read::launch_reading;
 use read::reading::Reading;
 use std::str::FromStr;
@@ -8,7 +12,10 @@ use std::str::FromStr;
 #[derive(Debug, Args)]
 #[command(version, about, long_about = None)]
 pub struct SetArgs {
-    /// The book to set
+    /// The reference to set, like "John 3:16", instead of the book, chapter and verse
+    #[arg(long, conflicts_with_all = ["book", "chapter", "verse"])]
+    pub reference: Option<ScriptureReference>,
+    /// The book to set, by its name, abbreviation or OSIS/USFM code
     #[arg(long)]
     pub book: Option<String>,
     /// The chapter to set
@@ -17,83 +24,276 @@ pub struct SetArgs {
     /// The verse to set
     #[arg(long)]
     pub verse: Option<u8>,
+    /// The versification of the chapter and verse to set
+    #[arg(long)]
+    pub versification: Option<Versification>,
+    /// The canon whose books are read
+    #[arg(long)]
+    pub canon: Option<Canon>,
 }
 
 #[derive(Debug, thiserror::Error)]
 pub enum SetError {
     #[error("The setting ordered is invalid. Book, chapter or verse is invalid.")]
     ReadingInvalid,
+    #[error("This book is unknown: {0}")]
+    BookUnknown(String),
 }
 
-pub fn main(path: std::path::PathBuf, args: &SetArgs) -> anyhow::Result<()> {
+pub fn main(
+    path: std::path::PathBuf,
+    pack: Option<std::path::PathBuf>,
+    boundary: BoundaryPolicy,
+    args: &SetArgs,
+) -> anyhow::Result<()> {
+    let pack_bible: Option<Bible> = match pack {
+        Some(pack_path) => Some(Bible::load_pack(&pack_path)?),
+        None => None,
+    };
+    let bible: &Bible = pack_bible.as_ref().unwrap_or(&BIBLE);
+
+    let book: Option<BibleEnum> = match &args.book {
+        Some(book_arg) => Some(
+            BibleEnum::from_str(book_arg).map_err(|_| SetError::BookUnknown(book_arg.clone()))?,
+        ),
+        None => None,
+    };
+    let mut chapter_arg: Option<u8> = args.chapter;
+    let mut verse_arg: Option<u8> = args.verse;
+
+    // A reference sets its first verse
+    let book = match args.reference {
+        Some(reference) => {
+            let first_verse: VerseRef = reference.first_verse();
+            chapter_arg = Some(first_verse.chapter.into());
+            verse_arg = Some(first_verse.verse.into());
+            Some(first_verse.book)
+        }
+        None => book,
+    };
+
     let client = launch_reading(1, path);
 
     let reading: Result<Reading, SetError> = match client.get_reading_from_file() {
         Ok(existant_reading) => {
             // If a reading is getted by a file
             // Get Book if there is
-            let book: BibleEnum = match &args.book {
-                Some(book_arg) => match BibleEnum::from_str(book_arg) {
-                    Ok(book_enum) => book_enum,
-                    Err(_) => *existant_reading.current_book(),
-                },
-                None => *existant_reading.current_book(),
-            };
+            let book: BibleEnum = book.unwrap_or(*existant_reading.current_book());
             // Get Chapter if there is
-            let chapter: ChapterNumber = match args.chapter {
-                Some(number) => ChapterNumber::try_from(number)
-                    .unwrap_or_else(|_| *existant_reading.current_chapter()),
+            let chapter: ChapterNumber = match chapter_arg {
+                Some(number) => {
+                    ChapterNumber::try_from(number).map_err(|_| SetError::ReadingInvalid)?
+                }
                 None => *existant_reading.current_chapter(),
             };
             // Get Verse if there is
-            let verse: Verse = match args.verse {
-                Some(number) => {
-                    Verse::try_from(number).unwrap_or_else(|_| *existant_reading.current_verse())
-                }
+            let verse: Verse = match verse_arg {
+                Some(number) => Verse::try_from(number).map_err(|_| SetError::ReadingInvalid)?,
                 None => *existant_reading.current_verse(),
             };
 
-            let mut new_reading = existant_reading.clone();
+            let verse_ref =
+                to_default_versification(&args.versification, VerseRef::new(book, chapter, verse))?;
 
-            new_reading
-                .modify_reading(book, chapter, verse)
-                .map_err(|_| SetError::ReadingInvalid)?;
+            // The reading keeps its cycles and boundary policy
+            let mut new_reading: Reading = existant_reading.clone();
+            let canon: Canon = args.canon.unwrap_or(*existant_reading.canon());
+
+            // The canons being nested, either the new book is in the current
+            // canon or the current book is in the new canon
+            if new_reading.canon().contains(&verse_ref.book) {
+                new_reading
+                    .modify_reading_in(verse_ref.book, verse_ref.chapter, verse_ref.verse, bible)
+                    .and_then(|_| new_reading.set_canon_in(canon, bible))
+            } else {
+                new_reading.set_canon_in(canon, bible).and_then(|_| {
+                    new_reading.modify_reading_in(
+                        verse_ref.book,
+                        verse_ref.chapter,
+                        verse_ref.verse,
+                        bible,
+                    )
+                })
+            }
+            .map_err(|_| SetError::ReadingInvalid)?;
 
             Ok(new_reading)
         }
         Err(_) => {
             // If there is no reading saved, create it
-            let book = match &args.book {
-                Some(book_arg) => {
-                    if let Ok(book_enum) = BibleEnum::from_str(book_arg) {
-                        book_enum
-                    } else {
-                        BibleEnum::Genesis
-                    }
-                }
-                None => BibleEnum::Genesis,
-            };
+            let book = book.unwrap_or(BibleEnum::Genesis);
 
-            let chapter = match args.chapter {
-                Some(chapter_arg) => ChapterNumber::try_from(chapter_arg).unwrap(),
-                None => ChapterNumber::try_from(1u8).unwrap(),
-            };
+            let chapter = ChapterNumber::try_from(chapter_arg.unwrap_or(1))
+                .map_err(|_| SetError::ReadingInvalid)?;
 
-            let verse = match args.verse {
-                Some(verse_arg) => Verse::try_from(verse_arg).unwrap(),
-                None => Verse::try_from(1u8).unwrap(),
-            };
+            let verse =
+                Verse::try_from(verse_arg.unwrap_or(1)).map_err(|_| SetError::ReadingInvalid)?;
+
+            let verse_ref =
+                to_default_versification(&args.versification, VerseRef::new(book, chapter, verse))?;
 
-            match Reading::new(book, chapter, verse) {
-                Ok(reading) => Ok(reading),
+            match Reading::with_canon_in(
+                args.canon.unwrap_or_default(),
+                verse_ref.book,
+                verse_ref.chapter,
+                verse_ref.verse,
+                bible,
+            ) {
+                Ok(mut reading) => {
+                    reading.set_boundary_policy(boundary);
+                    Ok(reading)
+                }
                 Err(_) => Err(SetError::ReadingInvalid),
             }
         }
     };
 
-    client.set_current_reading(reading.unwrap())?;
+    client.change_current_reading(reading?, "set")?;
 
     client.save_reading_in_file()?;
 
     Ok(())
 }
+
+fn to_default_versification(
+    versification: &Option<Versification>,
+    verse_ref: VerseRef,
+) -> Result<VerseRef, SetError> {
+    match versification {
+        Some(versification) => versification
+            .map_to(Versification::Kjv, verse_ref)
+            .map_err(|_| SetError::ReadingInvalid),
+        None => Ok(verse_ref),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_set_keeps_cycles_and_policy() {
+        let path =
+            std::env::temp_dir().join(format!("bible_read_test_set_{}.ron", std::process::id()));
+
+        let mut reading = Reading::new(
+            BibleEnum::Revelation,
+            22u8.try_into().unwrap(),
+            21u8.try_into().unwrap(),
+        )
+        .unwrap();
+        reading.next_verse(&1).unwrap();
+        reading.set_boundary_policy(BoundaryPolicy::Clamp);
+        assert_eq!(reading.cycles(), 1);
+
+        let client = launch_reading(1, path.clone());
+        client.change_current_reading(reading, "next").unwrap();
+        client.save_reading_in_file().unwrap();
+
+        main(
+            path.clone(),
+            None,
+            BoundaryPolicy::default(),
+            &SetArgs {
+                reference: None,
+                book: Some("tobit".to_string()),
+                chapter: Some(3),
+                verse: Some(16),
+                versification: None,
+                canon: Some(Canon::Catholic),
+            },
+        )
+        .unwrap();
+
+        let reading = launch_reading(1, path.clone())
+            .get_reading_from_file()
+            .unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(*reading.current_book(), BibleEnum::Tobit);
+        assert_eq!(*reading.canon(), Canon::Catholic);
+        assert_eq!(reading.cycles(), 1);
+        assert_eq!(reading.boundary_policy(), BoundaryPolicy::Clamp);
+    }
+
+    #[test]
+    fn test_set_chapter_zero_without_save() {
+        let path = std::env::temp_dir().join(format!(
+            "bible_read_test_set_zero_{}.ron",
+            std::process::id()
+        ));
+        let args = |chapter: u8, verse: u8| SetArgs {
+            reference: None,
+            book: Some("gen".to_string()),
+            chapter: Some(chapter),
+            verse: Some(verse),
+            versification: None,
+            canon: None,
+        };
+
+        for (chapter, verse) in [(0, 1), (1, 0)] {
+            let error = main(
+                path.clone(),
+                None,
+                BoundaryPolicy::default(),
+                &args(chapter, verse),
+            )
+            .unwrap_err();
+
+            assert!(matches!(
+                error.downcast_ref::<SetError>(),
+                Some(SetError::ReadingInvalid)
+            ));
+            assert!(!path.exists());
+        }
+    }
+
+    #[test]
+    fn test_set_chapter_zero_with_save() {
+        let path = std::env::temp_dir().join(format!(
+            "bible_read_test_set_zero_saved_{}.ron",
+            std::process::id()
+        ));
+        let reading = Reading::new(
+            BibleEnum::John,
+            3u8.try_into().unwrap(),
+            16u8.try_into().unwrap(),
+        )
+        .unwrap();
+
+        let client = launch_reading(1, path.clone());
+        client
+            .change_current_reading(reading.clone(), "set")
+            .unwrap();
+        client.save_reading_in_file().unwrap();
+
+        for (chapter, verse) in [(Some(0), None), (None, Some(0))] {
+            let error = main(
+                path.clone(),
+                None,
+                BoundaryPolicy::default(),
+                &SetArgs {
+                    reference: None,
+                    book: None,
+                    chapter,
+                    verse,
+                    versification: None,
+                    canon: None,
+                },
+            )
+            .unwrap_err();
+
+            assert!(matches!(
+                error.downcast_ref::<SetError>(),
+                Some(SetError::ReadingInvalid)
+            ));
+        }
+
+        let saved = launch_reading(1, path.clone())
+            .get_reading_from_file()
+            .unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(saved, reading);
+    }
+}
@@ -1,15 +1,75 @@
+use bible::{
+    citation::{CitationFormatter, CitationStyle},
+    locale::{Locale, LocalizedDisplay},
+    versification::Versification,
+    Bible, BIBLE,
+};
 use clap::Args;
 use read::{launch_reading, SaveServerError};
 
 #[derive(Debug, Args)]
 #[command(version, about, long_about = None)]
-pub struct ShowArgs {}
+pub struct ShowArgs {
+    /// Show the text of the current verse
+    #[arg(short, long)]
+    text: bool,
+    /// Show the reading numbered in this versification
+    #[arg(long)]
+    versification: Option<Versification>,
+    /// Write the reading in a citation style: long, sbl, osis or usfm
+    #[arg(long)]
+    style: Option<CitationStyle>,
+}
 
-pub fn main(path: std::path::PathBuf, args: &ShowArgs) -> anyhow::Result<()> {
+pub fn main(
+    path: std::path::PathBuf,
+    pack: Option<std::path::PathBuf>,
+    locale: Locale,
+    args: &ShowArgs,
+) -> anyhow::Result<()> {
     let client = launch_reading(1, path);
 
+    let pack_bible: Option<Bible> = match pack {
+        Some(pack_path) => Some(Bible::load_pack(&pack_path)?),
+        None => None,
+    };
+    let bible: &Bible = pack_bible.as_ref().unwrap_or(&BIBLE);
+
     match client.get_reading_from_file() {
-        Ok(reading) => println!("{}", reading),
+        Ok(reading) => {
+            match (args.versification, args.style) {
+                (Some(versification), Some(style)) => println!(
+                    "{}",
+                    CitationFormatter::new(style)
+                        .with_locale(locale)
+                        .format_verse(&reading.in_versification(versification)?)
+                ),
+                (Some(versification), None) => println!(
+                    "{}",
+                    reading.in_versification(versification)?.localized(locale)
+                ),
+                (None, Some(style)) => println!(
+                    "{}",
+                    reading.cite(&CitationFormatter::new(style).with_locale(locale))
+                ),
+                (None, None) => println!("{}", reading.localized(locale)),
+            }
+
+            if args.text {
+                match bible.verse_text(
+                    *reading.current_book(),
+                    *reading.current_chapter(),
+                    *reading.current_verse(),
+                ) {
+                    Ok(text) => println!("{}", text),
+                    Err(error) => println!("{}", error),
+                }
+            }
+
+            if reading.cycles() > 0 {
+                println!("Cycles completed: {}", reading.cycles());
+            }
+        }
         Err(SaveServerError::FailedToGetSave) => {
             println!("There was a problem in getting save. Have you save your reading ?")
         }
@@ -0,0 +1,48 @@
+use bible::sword::SwordLibrary;
+use clap::Args;
+
+#[derive(Debug, Args)]
+#[command(version, about, long_about = None)]
+pub struct SwordArgs {
+    /// The module to write as a data pack, like "KJV", instead of listing the
+    /// installed modules
+    #[arg(requires = "output")]
+    module: Option<String>,
+    /// The directory where the data pack is written
+    #[arg(short, long)]
+    output: Option<std::path::PathBuf>,
+    /// The SWORD library, instead of $SWORD_PATH or ~/.sword
+    #[arg(long)]
+    library: Option<std::path::PathBuf>,
+}
+
+pub fn main(args: &SwordArgs) -> anyhow::Result<()> {
+    let root = match &args.library {
+        Some(library) => library.clone(),
+        None => SwordLibrary::default_root()
+            .ok_or_else(|| anyhow::anyhow!("No SWORD library was found"))?,
+    };
+    let library = SwordLibrary::open(&root)?;
+    for path in &library.skipped {
+        println!("Skipped {}", path.display());
+    }
+
+    let (Some(module), Some(output)) = (&args.module, &args.output) else {
+        for conf in library.bibles() {
+            println!("{}: {}", conf.name, conf.description().unwrap_or_default());
+        }
+        return Ok(());
+    };
+
+    let pack = library.module(module)?.to_pack()?;
+    pack.save(output)?;
+
+    println!(
+        "{} books of {} written into {}",
+        pack.versification.len(),
+        module,
+        output.display()
+    );
+
+    Ok(())
+}
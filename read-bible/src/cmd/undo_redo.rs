@@ -0,0 +1,24 @@
+use bible::locale::{Locale, LocalizedDisplay};
+use read::launch_reading;
+
+pub enum Action {
+    Undo,
+    Redo,
+}
+
+pub fn main(path: std::path::PathBuf, locale: Locale, action: Action) -> anyhow::Result<()> {
+    let client = launch_reading(1, path);
+
+    client.get_reading_from_file()?;
+
+    let reading = match action {
+        Action::Undo => client.undo()?,
+        Action::Redo => client.redo()?,
+    };
+
+    client.save_reading_in_file()?;
+
+    println!("{}", reading.localized(locale));
+
+    Ok(())
+}
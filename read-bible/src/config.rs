@@ -0,0 +1,22 @@
+use bible::locale::Locale;
+use read::boundary::BoundaryPolicy;
+use serde::{Deserialize, Serialize};
+
+const APP_NAME: &str = "bible-read";
+
+/// The settings of the user, saved in the configuration directory
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct Config {
+    /// The language of the book names
+    #[serde(default)]
+    pub locale: Locale,
+    /// What going next or previous does beyond the first or the last book in a
+    /// new reading: wrap, clamp or error
+    #[serde(default)]
+    pub boundary: BoundaryPolicy,
+}
+
+/// Load the configuration, creating it with the default values if needed
+pub fn load() -> Result<Config, confy::ConfyError> {
+    confy::load(APP_NAME, None)
+}
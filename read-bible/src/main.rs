@@ -1,18 +1,39 @@
 use clap::Parser;
 use cli::{Cli, Commands};
-use cmd::next_previous::Direction;
+use cmd::{next_previous::Direction, undo_redo::Action};
 
 mod cli;
+/// Kept for packaging, which writes the man page
+pub use cli::create_doc_man;
 mod cmd;
+mod config;
 
 fn main() -> anyhow::Result<()> {
     let cli = Cli::parse();
+    // A configuration that cannot be read must not prevent reading
+    let config = config::load().unwrap_or_default();
+    let locale = cli.locale.unwrap_or(config.locale);
 
     match &cli.command {
-        Commands::Show(args) => cmd::show::main(cli.path, args)?,
-        Commands::Set(args) => cmd::set::main(cli.path, args)?,
-        Commands::Next(args) => cmd::next_previous::main(cli.path, args, Direction::Next)?,
-        Commands::Previous(args) => cmd::next_previous::main(cli.path, args, Direction::Previous)?,
+        Commands::Show(args) => cmd::show::main(cli.path, cli.pack, locale, args)?,
+        Commands::Set(args) => cmd::set::main(cli.path, cli.pack, config.boundary, args)?,
+        Commands::Next(args) => {
+            cmd::next_previous::main(cli.path, cli.pack, config.boundary, args, Direction::Next)?
+        }
+        Commands::Previous(args) => cmd::next_previous::main(
+            cli.path,
+            cli.pack,
+            config.boundary,
+            args,
+            Direction::Previous,
+        )?,
+        Commands::Info(args) => cmd::info::main(cli.pack, locale, args)?,
+        Commands::Import(args) => cmd::import::main(args)?,
+        Commands::Sword(args) => cmd::sword::main(args)?,
+        Commands::History(args) => cmd::history::main(cli.path, locale, args)?,
+        Commands::Undo => cmd::undo_redo::main(cli.path, locale, Action::Undo)?,
+        Commands::Redo => cmd::undo_redo::main(cli.path, locale, Action::Redo)?,
+        Commands::Plan(args) => cmd::plan::main(cli.pack, locale, args)?,
     };
 
     Ok(())
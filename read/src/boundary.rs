@@ -0,0 +1,64 @@
+use serde::{Deserialize, Serialize};
+
+/// What navigating does beyond the first or the last book of the canon
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum BoundaryPolicy {
+    /// Start over from the other end, counting the completed cycles
+    #[default]
+    Wrap,
+    /// Stop at the first or the last verse
+    Clamp,
+    /// Fail with [`crate::reading::ReadingError::EndOfBible`] or
+    /// [`crate::reading::ReadingError::StartOfBible`]
+    Error,
+}
+
+#[derive(Debug, thiserror::Error, PartialEq)]
+#[error("This boundary policy is unknown: {0}")]
+pub struct BoundaryPolicyError(String);
+
+impl std::str::FromStr for BoundaryPolicy {
+    type Err = BoundaryPolicyError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "wrap" => Ok(BoundaryPolicy::Wrap),
+            "clamp" => Ok(BoundaryPolicy::Clamp),
+            "error" => Ok(BoundaryPolicy::Error),
+            _ => Err(BoundaryPolicyError(s.to_string())),
+        }
+    }
+}
+
+impl std::fmt::Display for BoundaryPolicy {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            BoundaryPolicy::Wrap => "wrap",
+            BoundaryPolicy::Clamp => "clamp",
+            BoundaryPolicy::Error => "error",
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_str() {
+        for policy in [
+            BoundaryPolicy::Wrap,
+            BoundaryPolicy::Clamp,
+            BoundaryPolicy::Error,
+        ] {
+            assert_eq!(policy.to_string().parse(), Ok(policy));
+        }
+
+        assert_eq!("Clamp".parse(), Ok(BoundaryPolicy::Clamp));
+        assert_eq!(
+            "stop".parse::<BoundaryPolicy>(),
+            Err(BoundaryPolicyError("stop".to_string()))
+        );
+    }
+}
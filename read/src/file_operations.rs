@@ -4,17 +4,35 @@ use std::{
     sync::Arc,
 };
 
-use crate::reading::Reading;
+use serde::{Deserialize, Serialize};
 
-pub fn get_reading_in_file(path: Arc<std::path::PathBuf>) -> std::io::Result<Option<Reading>> {
+use crate::{history::History, reading::Reading};
+
+/// What the save file holds
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct SaveData {
+    reading: Reading,
+    #[serde(default)]
+    history: History,
+}
+
+/// Get the reading and its history from a save file, which may also be a
+/// reading alone, as saved before the history was kept
+pub fn get_reading_in_file(
+    path: Arc<std::path::PathBuf>,
+) -> std::io::Result<Option<(Reading, History)>> {
     let mut file = OpenOptions::new().read(true).open(&*path)?;
 
     let mut read_file = String::new();
 
     file.read_to_string(&mut read_file)?;
 
+    if let Ok(save_data) = ron::from_str::<SaveData>(&read_file) {
+        return Ok(Some((save_data.reading, save_data.history)));
+    }
+
     if let Ok(reading) = ron::from_str(&read_file) {
-        return Ok(Some(reading));
+        return Ok(Some((reading, History::default())));
     }
 
     Ok(None)
@@ -23,6 +41,7 @@ pub fn get_reading_in_file(path: Arc<std::path::PathBuf>) -> std::io::Result<Opt
 pub fn save_reading_in_file(
     path: Arc<std::path::PathBuf>,
     reading: &Reading,
+    history: &History,
 ) -> std::io::Result<()> {
     let mut file = OpenOptions::new()
         .write(true)
@@ -30,7 +49,11 @@ pub fn save_reading_in_file(
         .truncate(true)
         .open(&*path)?;
 
-    let data = ron::to_string(reading).unwrap();
+    let data = ron::to_string(&SaveData {
+        reading: reading.clone(),
+        history: history.clone(),
+    })
+    .unwrap();
 
     file.write_all(data.as_bytes())?;
 
@@ -41,6 +64,7 @@ pub fn save_reading_in_file(
 mod tests {
     use bible::bible_enum::BibleEnum;
     use book::book_components::{chapter_number::ChapterNumber, verse::Verse};
+    use chrono::Utc;
 
     use super::*;
 
@@ -51,10 +75,33 @@ mod tests {
         let verse = Verse::try_from(2u8).unwrap();
         let chapter_number = ChapterNumber::try_from(1u8).unwrap();
         let reading: Reading = Reading::new(BibleEnum::Genesis, chapter_number, verse).unwrap();
+        let mut next_reading: Reading = reading.clone();
+        next_reading.next_verse(&1).unwrap();
+        let mut history = History::default();
+        history.record(reading, next_reading.clone(), "next 1 verse", Utc::now());
+
+        save_reading_in_file(path.clone(), &next_reading, &history).unwrap();
 
-        save_reading_in_file(path.clone(), &reading).unwrap();
+        let result_reading_file = get_reading_in_file(path).unwrap();
+        assert_eq!(result_reading_file, Some((next_reading, history)));
+    }
 
-        let result_reading_file: Option<Reading> = get_reading_in_file(path).unwrap();
-        assert_eq!(result_reading_file, Some(reading));
+    #[test]
+    fn test_reading_without_history() {
+        let path: Arc<std::path::PathBuf> = Arc::new(std::env::temp_dir().join(format!(
+            "bible_read_test_without_history_{}.ron",
+            std::process::id()
+        )));
+        std::fs::write(
+            &*path,
+            "(current_book: exodus, current_chapter: (2), current_verse: (3), canon: protestant)",
+        )
+        .unwrap();
+
+        let (reading, history) = get_reading_in_file(path.clone()).unwrap().unwrap();
+        std::fs::remove_file(&*path).unwrap();
+
+        assert_eq!(reading.to_string(), "Exodus 2:3");
+        assert_eq!(history, History::default());
     }
 }
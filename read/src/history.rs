@@ -0,0 +1,169 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::reading::Reading;
+
+/// A change of the reading, from a position to another
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+pub struct HistoryEntry {
+    pub timestamp: DateTime<Utc>,
+    pub from: Reading,
+    pub to: Reading,
+    /// The command which made the change, like "next 2 chapter" or "undo"
+    pub command: String,
+}
+
+/// The journal of the changes of a reading, which can be undone and redone
+#[derive(Debug, PartialEq, Clone, Default, Serialize, Deserialize)]
+pub struct History {
+    entries: Vec<HistoryEntry>,
+    /// The entries which can be undone, the last one first
+    #[serde(default)]
+    undoable: Vec<usize>,
+    /// The entries undone which can be redone, the last one first
+    #[serde(default)]
+    redoable: Vec<usize>,
+}
+
+impl History {
+    /// The changes, from the oldest to the newest
+    pub fn entries(&self) -> &[HistoryEntry] {
+        &self.entries
+    }
+
+    pub fn can_undo(&self) -> bool {
+        !self.undoable.is_empty()
+    }
+
+    pub fn can_redo(&self) -> bool {
+        !self.redoable.is_empty()
+    }
+
+    /// Record a change made by a command, which can no longer redo what was
+    /// undone. Nothing is recorded if the reading is unchanged.
+    pub fn record(
+        &mut self,
+        from: Reading,
+        to: Reading,
+        command: impl Into<String>,
+        timestamp: DateTime<Utc>,
+    ) {
+        if from == to {
+            return;
+        }
+
+        self.undoable.push(self.entries.len());
+        self.redoable.clear();
+        self.push(from, to, command.into(), timestamp);
+    }
+
+    /// Go back to the reading before the last change, recording it as an
+    /// "undo" entry
+    pub fn undo(&mut self, current: Reading, timestamp: DateTime<Utc>) -> Option<Reading> {
+        let index: usize = self.undoable.pop()?;
+        let reading: Reading = self.entries[index].from.clone();
+
+        self.redoable.push(index);
+        self.push(current, reading.clone(), "undo".to_string(), timestamp);
+
+        Some(reading)
+    }
+
+    /// Make again the last change undone, recording it as a "redo" entry
+    pub fn redo(&mut self, current: Reading, timestamp: DateTime<Utc>) -> Option<Reading> {
+        let index: usize = self.redoable.pop()?;
+        let reading: Reading = self.entries[index].to.clone();
+
+        self.undoable.push(index);
+        self.push(current, reading.clone(), "redo".to_string(), timestamp);
+
+        Some(reading)
+    }
+
+    /// The reading at a time, given by the last change before it, or by the
+    /// first change after it. None if nothing is recorded.
+    pub fn reading_at(&self, time: DateTime<Utc>) -> Option<&Reading> {
+        match self
+            .entries
+            .iter()
+            .rev()
+            .find(|entry| entry.timestamp <= time)
+        {
+            Some(entry) => Some(&entry.to),
+            None => self.entries.first().map(|entry| &entry.from),
+        }
+    }
+
+    fn push(&mut self, from: Reading, to: Reading, command: String, timestamp: DateTime<Utc>) {
+        self.entries.push(HistoryEntry {
+            timestamp,
+            from,
+            to,
+            command,
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bible::bible_enum::BibleEnum;
+    use chrono::TimeZone;
+
+    use super::*;
+
+    fn reading(chapter: u8) -> Reading {
+        Reading::new(
+            BibleEnum::Genesis,
+            chapter.try_into().unwrap(),
+            1u8.try_into().unwrap(),
+        )
+        .unwrap()
+    }
+
+    fn day(day: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2024, 3, day, 12, 0, 0).unwrap()
+    }
+
+    #[test]
+    fn test_undo_redo() {
+        let mut history = History::default();
+
+        assert!(!history.can_undo());
+        assert_eq!(history.undo(reading(1), day(1)), None);
+
+        history.record(reading(1), reading(2), "next chapter", day(1));
+        history.record(reading(2), reading(3), "next chapter", day(2));
+        history.record(reading(3), reading(3), "next chapter", day(2));
+        assert_eq!(history.entries().len(), 2);
+
+        assert_eq!(history.undo(reading(3), day(3)), Some(reading(2)));
+        assert_eq!(history.undo(reading(2), day(3)), Some(reading(1)));
+        assert_eq!(history.undo(reading(1), day(3)), None);
+        assert_eq!(history.redo(reading(1), day(4)), Some(reading(2)));
+        assert!(history.can_redo());
+        assert_eq!(history.entries().len(), 5);
+        assert_eq!(history.entries()[4].command, "redo");
+
+        // A new change cannot redo what was undone
+        history.record(reading(2), reading(10), "set", day(5));
+        assert!(!history.can_redo());
+        assert_eq!(history.redo(reading(10), day(5)), None);
+        assert_eq!(history.undo(reading(10), day(6)), Some(reading(2)));
+        assert_eq!(history.undo(reading(2), day(6)), Some(reading(1)));
+    }
+
+    #[test]
+    fn test_reading_at() {
+        let mut history = History::default();
+
+        assert_eq!(history.reading_at(day(1)), None);
+
+        history.record(reading(1), reading(2), "next chapter", day(2));
+        history.record(reading(2), reading(5), "set", day(4));
+
+        assert_eq!(history.reading_at(day(1)), Some(&reading(1)));
+        assert_eq!(history.reading_at(day(2)), Some(&reading(2)));
+        assert_eq!(history.reading_at(day(3)), Some(&reading(2)));
+        assert_eq!(history.reading_at(day(9)), Some(&reading(5)));
+    }
+}
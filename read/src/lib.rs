@@ -1,4 +1,7 @@
+pub mod boundary;
 mod file_operations;
+pub mod history;
+pub mod plan;
 pub mod reading;
 
 use std::{
@@ -9,7 +12,9 @@ use std::{
     thread::spawn,
 };
 
+use chrono::Utc;
 use file_operations::{get_reading_in_file, save_reading_in_file};
+use history::History;
 use reading::Reading;
 
 #[derive(Debug, Clone)]
@@ -29,6 +34,20 @@ enum Command {
         reading: Reading,
         response_channel: SyncSender<()>,
     },
+    ChangeCurrentReading {
+        reading: Reading,
+        command: String,
+        response_channel: SyncSender<()>,
+    },
+    Undo {
+        response_channel: SyncSender<Result<Reading, SaveServerError>>,
+    },
+    Redo {
+        response_channel: SyncSender<Result<Reading, SaveServerError>>,
+    },
+    GetHistory {
+        response_channel: SyncSender<Arc<Mutex<History>>>,
+    },
 }
 
 #[derive(Debug, thiserror::Error)]
@@ -41,6 +60,10 @@ pub enum SaveServerError {
     FailedToGetSave,
     #[error("No data to save, reading is none")]
     NoDataToSave,
+    #[error("There is no change to undo")]
+    NothingToUndo,
+    #[error("There is no change to redo")]
+    NothingToRedo,
 }
 
 #[derive(Clone)]
@@ -89,6 +112,7 @@ impl ReadingSaveClient {
         Ok(response_receiver.recv().unwrap())
     }
 
+    /// Set the current reading, without recording the change in the history
     pub fn set_current_reading(&self, reading: Reading) -> Result<(), SaveServerError> {
         let (response_sender, response_receiver) = sync_channel(1);
         self.sender
@@ -101,6 +125,61 @@ impl ReadingSaveClient {
         response_receiver.recv().unwrap();
         Ok(())
     }
+
+    /// Set the current reading, recording the change made by a command, like
+    /// "next 2 chapter", in the history
+    pub fn change_current_reading(
+        &self,
+        reading: Reading,
+        command: impl Into<String>,
+    ) -> Result<(), SaveServerError> {
+        let (response_sender, response_receiver) = sync_channel(1);
+        self.sender
+            .try_send(Command::ChangeCurrentReading {
+                reading,
+                command: command.into(),
+                response_channel: response_sender,
+            })
+            .map_err(|_| SaveServerError::OverloadedError)?;
+
+        response_receiver.recv().unwrap();
+        Ok(())
+    }
+
+    /// Go back to the reading before the last change, and return it
+    pub fn undo(&self) -> Result<Reading, SaveServerError> {
+        let (response_sender, response_receiver) = sync_channel(1);
+        self.sender
+            .try_send(Command::Undo {
+                response_channel: response_sender,
+            })
+            .map_err(|_| SaveServerError::OverloadedError)?;
+
+        response_receiver.recv().unwrap()
+    }
+
+    /// Make again the last change undone, and return the reading
+    pub fn redo(&self) -> Result<Reading, SaveServerError> {
+        let (response_sender, response_receiver) = sync_channel(1);
+        self.sender
+            .try_send(Command::Redo {
+                response_channel: response_sender,
+            })
+            .map_err(|_| SaveServerError::OverloadedError)?;
+
+        response_receiver.recv().unwrap()
+    }
+
+    pub fn get_history(&self) -> Result<Arc<Mutex<History>>, SaveServerError> {
+        let (response_sender, response_receiver) = sync_channel(1);
+        self.sender
+            .try_send(Command::GetHistory {
+                response_channel: response_sender,
+            })
+            .map_err(|_| SaveServerError::OverloadedError)?;
+
+        Ok(response_receiver.recv().unwrap())
+    }
 }
 
 pub fn launch_reading(capacity: usize, path: std::path::PathBuf) -> ReadingSaveClient {
@@ -115,6 +194,8 @@ pub fn launch_reading(capacity: usize, path: std::path::PathBuf) -> ReadingSaveC
 fn server_reading(receiver: Receiver<Command>) {
     // The current Reading Value
     let current_reading: Arc<Mutex<Option<Reading>>> = Arc::new(Mutex::new(None));
+    // The changes of the reading
+    let current_history: Arc<Mutex<History>> = Arc::new(Mutex::new(History::default()));
 
     loop {
         match receiver.recv() {
@@ -124,11 +205,14 @@ fn server_reading(receiver: Receiver<Command>) {
             }) => {
                 match get_reading_in_file(path) {
                     Ok(function_result) => {
-                        if let Some(reading_result) = function_result {
+                        if let Some((reading_result, history_result)) = function_result {
                             // Save the reading value in current value
                             if let Ok(ref mut reading_value) = current_reading.try_lock() {
                                 **reading_value = Some(reading_result.clone());
                             }
+                            if let Ok(ref mut history_value) = current_history.try_lock() {
+                                **history_value = history_result;
+                            }
 
                             let _ = response_channel.send(Ok(reading_result));
                         }
@@ -145,10 +229,11 @@ fn server_reading(receiver: Receiver<Command>) {
                 response_channel,
             }) => {
                 let reading_lock = current_reading.lock().unwrap();
+                let history_lock = current_history.lock().unwrap();
 
                 match *reading_lock {
                     Some(ref reading_value) => {
-                        if save_reading_in_file(path, reading_value).is_ok() {
+                        if save_reading_in_file(path, reading_value, &history_lock).is_ok() {
                             let _ = response_channel.send(Ok(()));
                         } else {
                             // Return failed
@@ -174,6 +259,56 @@ fn server_reading(receiver: Receiver<Command>) {
 
                 let _ = response_channel.send(());
             }
+            Ok(Command::ChangeCurrentReading {
+                reading,
+                command,
+                response_channel,
+            }) => {
+                let mut reading_lock = current_reading.lock().unwrap();
+
+                // The first reading is not a change
+                if let Some(previous_reading) = reading_lock.replace(reading.clone()) {
+                    current_history.lock().unwrap().record(
+                        previous_reading,
+                        reading,
+                        command,
+                        Utc::now(),
+                    );
+                }
+
+                let _ = response_channel.send(());
+            }
+            Ok(Command::Undo { response_channel }) => {
+                let mut reading_lock = current_reading.lock().unwrap();
+                let mut history_lock = current_history.lock().unwrap();
+
+                let result = reading_lock
+                    .clone()
+                    .and_then(|reading| history_lock.undo(reading, Utc::now()))
+                    .ok_or(SaveServerError::NothingToUndo);
+                if let Ok(ref reading) = result {
+                    *reading_lock = Some(reading.clone());
+                }
+
+                let _ = response_channel.send(result);
+            }
+            Ok(Command::Redo { response_channel }) => {
+                let mut reading_lock = current_reading.lock().unwrap();
+                let mut history_lock = current_history.lock().unwrap();
+
+                let result = reading_lock
+                    .clone()
+                    .and_then(|reading| history_lock.redo(reading, Utc::now()))
+                    .ok_or(SaveServerError::NothingToRedo);
+                if let Ok(ref reading) = result {
+                    *reading_lock = Some(reading.clone());
+                }
+
+                let _ = response_channel.send(result);
+            }
+            Ok(Command::GetHistory { response_channel }) => {
+                let _ = response_channel.send(current_history.clone());
+            }
             Err(_) => {
                 break;
             }
@@ -207,4 +342,52 @@ mod tests {
 
         assert_eq!(Some(reading), *current_reading_lock);
     }
+
+    #[test]
+    fn test_undo_redo() {
+        let path = std::env::temp_dir().join(format!(
+            "bible_read_test_history_{}.ron",
+            std::process::id()
+        ));
+        let client = launch_reading(1, path.clone());
+
+        let reading = Reading::new(
+            BibleEnum::Genesis,
+            ChapterNumber::try_from(1u8).unwrap(),
+            Verse::try_from(1u8).unwrap(),
+        )
+        .unwrap();
+        let mut next_reading = reading.clone();
+        next_reading.next_chapter(&1).unwrap();
+
+        assert!(matches!(client.undo(), Err(SaveServerError::NothingToUndo)));
+
+        client
+            .change_current_reading(reading.clone(), "set")
+            .unwrap();
+        client
+            .change_current_reading(next_reading.clone(), "next 1 chapter")
+            .unwrap();
+
+        assert_eq!(client.undo().unwrap(), reading);
+        assert!(matches!(client.undo(), Err(SaveServerError::NothingToUndo)));
+        assert_eq!(client.redo().unwrap(), next_reading);
+        assert_eq!(
+            *client.get_current_reading().unwrap().lock().unwrap(),
+            Some(next_reading)
+        );
+
+        let history = client.get_history().unwrap();
+        let commands: Vec<String> = history
+            .lock()
+            .unwrap()
+            .entries()
+            .iter()
+            .map(|entry| entry.command.clone())
+            .collect();
+        assert_eq!(commands, vec!["next 1 chapter", "undo", "redo"]);
+
+        // Nothing is saved here, but no file must be left if it ever is
+        let _ = std::fs::remove_file(&path);
+    }
 }
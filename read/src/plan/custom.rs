@@ -0,0 +1,443 @@
+//! Plans written by their readers.
+//!
+//! A plan is a RON file, like the save file, giving its name and the
+//! references to read each day:
+//!
+//! ```ron
+//! (
+//!     name: "Lent",
+//!     days: [
+//!         ["Matthew 4:1-11", "Psalms 51"],
+//!         [],
+//!         ["Mark 1-2"],
+//!     ],
+//! )
+//! ```
+//!
+//! The same fields may be written in TOML, and CSV lines of "day,references"
+//! may list the references of a day, like `3,"Mark 1-2; Psalms 1"`.
+
+use std::path::{Path, PathBuf};
+
+use bible::{
+    passage::{Passage, PassageError},
+    reference::{ReferenceError, ScriptureReference},
+    Bible, BIBLE,
+};
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+
+use super::{ReadingPlan, Schedule};
+
+#[derive(Debug, thiserror::Error)]
+pub enum PlanFileError {
+    #[error("Cannot read {path}")]
+    Io {
+        path: PathBuf,
+        #[source]
+        source: std::io::Error,
+    },
+    #[error("{0} is not a RON, TOML or CSV file")]
+    UnknownFormat(PathBuf),
+    #[error("The plan cannot be read: {0}")]
+    Syntax(String),
+    #[error("Line {line}: {value} is not a day number, the first day being 1")]
+    InvalidDay { line: u64, value: String },
+    #[error("The plan has no day")]
+    NoDay,
+    #[error("Day {day}, \"{reference}\": {error}")]
+    InvalidReference {
+        day: usize,
+        reference: String,
+        error: ReferenceError,
+    },
+    #[error("Day {day}, \"{reference}\": {error}")]
+    InvalidPassage {
+        day: usize,
+        reference: String,
+        error: PassageError,
+    },
+}
+
+/// The formats a plan is read from
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PlanFormat {
+    Ron,
+    Toml,
+    Csv,
+}
+
+impl PlanFormat {
+    /// The format of a file, by its extension
+    pub fn from_path(path: &Path) -> Option<PlanFormat> {
+        let extension: String = path.extension()?.to_str()?.to_lowercase();
+
+        match extension.as_str() {
+            "ron" => Some(PlanFormat::Ron),
+            "toml" => Some(PlanFormat::Toml),
+            "csv" => Some(PlanFormat::Csv),
+            _ => None,
+        }
+    }
+}
+
+/// A plan as written in a file, whose days are lists of references, like
+/// "Genesis 1-3; Matthew 1"
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PlanDefinition {
+    pub name: String,
+    #[serde(default)]
+    pub schedule: Schedule,
+    pub days: Vec<Vec<String>>,
+}
+
+/// A line of a CSV plan
+#[derive(Debug, Deserialize)]
+struct CsvLine {
+    day: String,
+    references: String,
+}
+
+impl PlanDefinition {
+    /// Read a plan file in the format of its extension, a CSV plan being
+    /// named after its file
+    pub fn load(path: &Path) -> Result<PlanDefinition, PlanFileError> {
+        let format: PlanFormat = PlanFormat::from_path(path)
+            .ok_or_else(|| PlanFileError::UnknownFormat(path.to_path_buf()))?;
+        let source: String = std::fs::read_to_string(path).map_err(|source| PlanFileError::Io {
+            path: path.to_path_buf(),
+            source,
+        })?;
+
+        match format {
+            PlanFormat::Ron => PlanDefinition::from_ron(&source),
+            PlanFormat::Toml => PlanDefinition::from_toml(&source),
+            PlanFormat::Csv => PlanDefinition::from_csv(
+                &path.file_stem().unwrap_or_default().to_string_lossy(),
+                &source,
+            ),
+        }
+    }
+
+    pub fn from_ron(source: &str) -> Result<PlanDefinition, PlanFileError> {
+        ron::from_str(source).map_err(|error| PlanFileError::Syntax(error.to_string()))
+    }
+
+    pub fn from_toml(source: &str) -> Result<PlanDefinition, PlanFileError> {
+        toml::from_str(source).map_err(|error| PlanFileError::Syntax(error.to_string()))
+    }
+
+    /// Read lines of "day,references", after an optional header. The lines of
+    /// a day are added to it, and a day without a line has nothing to read.
+    pub fn from_csv(name: &str, source: &str) -> Result<PlanDefinition, PlanFileError> {
+        let mut reader = csv::ReaderBuilder::new()
+            .has_headers(false)
+            .trim(csv::Trim::All)
+            .from_reader(source.as_bytes());
+        let mut days: Vec<Vec<String>> = Vec::new();
+
+        for (index, record) in reader.records().enumerate() {
+            let record = record.map_err(|error| PlanFileError::Syntax(error.to_string()))?;
+            let line: u64 = record
+                .position()
+                .map_or(index as u64 + 1, |position| position.line());
+
+            if index == 0
+                && record
+                    .get(0)
+                    .is_some_and(|day| day.eq_ignore_ascii_case("day"))
+            {
+                continue;
+            }
+
+            let csv_line: CsvLine = record
+                .deserialize(None)
+                .map_err(|error| PlanFileError::Syntax(error.to_string()))?;
+            let day: usize = csv_line
+                .day
+                .parse::<u16>()
+                .ok()
+                .filter(|day| *day > 0)
+                .ok_or(PlanFileError::InvalidDay {
+                    line,
+                    value: csv_line.day,
+                })?
+                .into();
+
+            if days.len() < day {
+                days.resize(day, Vec::new());
+            }
+            days[day - 1].push(csv_line.references);
+        }
+
+        Ok(PlanDefinition {
+            name: name.to_string(),
+            schedule: Schedule::default(),
+            days,
+        })
+    }
+
+    pub fn to_ron(&self) -> String {
+        ron::ser::to_string_pretty(self, ron::ser::PrettyConfig::default()).unwrap()
+    }
+
+    /// Every mistake of the plan against `BIBLE`, like an unknown book or a
+    /// chapter out of range
+    pub fn errors(&self) -> Vec<PlanFileError> {
+        self.errors_in(&BIBLE)
+    }
+
+    pub fn errors_in(&self, bible: &Bible) -> Vec<PlanFileError> {
+        if self.days.is_empty() {
+            return vec![PlanFileError::NoDay];
+        }
+
+        self.days
+            .iter()
+            .enumerate()
+            .flat_map(|(index, references)| {
+                references.iter().filter_map(move |reference| {
+                    parse_references(index + 1, reference, bible).err()
+                })
+            })
+            .collect()
+    }
+
+    /// The plan of the passages of the references, from a date
+    pub fn to_plan(&self, start: NaiveDate) -> Result<ReadingPlan, PlanFileError> {
+        self.to_plan_in(start, &BIBLE)
+    }
+
+    /// The plan of the passages of the references, checked against a Bible,
+    /// failing at the first mistake
+    pub fn to_plan_in(
+        &self,
+        start: NaiveDate,
+        bible: &Bible,
+    ) -> Result<ReadingPlan, PlanFileError> {
+        if self.days.is_empty() {
+            return Err(PlanFileError::NoDay);
+        }
+
+        let mut days: Vec<Vec<Passage>> = Vec::with_capacity(self.days.len());
+
+        for (index, references) in self.days.iter().enumerate() {
+            let mut passages: Vec<Passage> = Vec::new();
+
+            for reference in references {
+                for scripture_reference in parse_references(index + 1, reference, bible)? {
+                    let passage = Passage::from_reference_in(&scripture_reference, bible).map_err(
+                        |error| PlanFileError::InvalidPassage {
+                            day: index + 1,
+                            reference: reference.clone(),
+                            error,
+                        },
+                    )?;
+                    passages.push(passage);
+                }
+            }
+
+            days.push(passages);
+        }
+
+        Ok(ReadingPlan::new(self.name.clone(), start, days).with_schedule(self.schedule))
+    }
+}
+
+fn parse_references(
+    day: usize,
+    reference: &str,
+    bible: &Bible,
+) -> Result<Vec<ScriptureReference>, PlanFileError> {
+    ScriptureReference::parse_list_in(reference, bible).map_err(|error| {
+        PlanFileError::InvalidReference {
+            day,
+            reference: reference.to_string(),
+            error,
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use bible::bible_enum::BibleEnum;
+
+    use super::*;
+
+    const LENT: &str = r#"(
+    name: "Lent",
+    days: [
+        ["Matthew 4:1-11", "Psalms 51"],
+        [],
+        ["Mark 1-2"],
+    ],
+)"#;
+
+    fn start() -> NaiveDate {
+        NaiveDate::from_ymd_opt(2025, 3, 5).unwrap()
+    }
+
+    #[test]
+    fn test_ron() {
+        let definition = PlanDefinition::from_ron(LENT).unwrap();
+
+        assert_eq!(definition.name, "Lent");
+        assert_eq!(definition.schedule, Schedule::Once);
+        assert!(definition.errors().is_empty());
+        assert_eq!(
+            PlanDefinition::from_ron(&definition.to_ron()).unwrap(),
+            definition
+        );
+
+        let plan = definition.to_plan(start()).unwrap();
+        assert_eq!(plan.days().len(), 3);
+        assert_eq!(plan.day(1).unwrap()[1].verse_count(), 19);
+        assert!(plan.day(2).unwrap().is_empty());
+        assert_eq!(
+            plan.reading_on(NaiveDate::from_ymd_opt(2025, 3, 7).unwrap())
+                .unwrap()
+                .1[0]
+                .end()
+                .to_string(),
+            "Mark 2:28"
+        );
+    }
+
+    #[test]
+    fn test_toml() {
+        let definition = PlanDefinition::from_toml(
+            r#"
+name = "Proverbs"
+schedule = "monthly"
+days = [["Proverbs 1"], ["Proverbs 2; Proverbs 3"]]
+"#,
+        )
+        .unwrap();
+
+        assert_eq!(definition.schedule, Schedule::Monthly);
+        assert_eq!(
+            definition.to_plan(start()).unwrap().day(2).unwrap().len(),
+            2
+        );
+        assert!(matches!(
+            PlanDefinition::from_toml("name = \"Proverbs\""),
+            Err(PlanFileError::Syntax(_))
+        ));
+    }
+
+    #[test]
+    fn test_csv() {
+        let definition = PlanDefinition::from_csv(
+            "gospels",
+            "day,references\n1,Matthew 1\n1,\"Mark 1:1-8, 14\"\n3,Luke 1\n",
+        )
+        .unwrap();
+
+        assert_eq!(definition.name, "gospels");
+        assert_eq!(
+            definition.days,
+            vec![
+                vec!["Matthew 1".to_string(), "Mark 1:1-8, 14".to_string()],
+                vec![],
+                vec!["Luke 1".to_string()],
+            ]
+        );
+        assert!(definition.errors().is_empty());
+
+        // The header is optional
+        assert_eq!(
+            PlanDefinition::from_csv("john", "1,John 1").unwrap().days,
+            vec![vec!["John 1".to_string()]]
+        );
+        assert!(matches!(
+            PlanDefinition::from_csv("john", "day,references\n1,John 1\n0,John 2"),
+            Err(PlanFileError::InvalidDay { line: 3, value }) if value == "0"
+        ));
+        assert!(matches!(
+            PlanDefinition::from_csv("john", "first,John 1"),
+            Err(PlanFileError::InvalidDay { line: 1, .. })
+        ));
+    }
+
+    #[test]
+    fn test_errors() {
+        let definition = PlanDefinition {
+            name: "Mistakes".to_string(),
+            schedule: Schedule::Once,
+            days: vec![
+                vec!["Genesis 1".to_string(), "Hezekiah 1".to_string()],
+                vec!["Jude 2-3".to_string()],
+                vec!["Psalms 151".to_string()],
+            ],
+        };
+
+        let errors = definition.errors();
+        assert_eq!(errors.len(), 2);
+        assert!(matches!(
+            &errors[0],
+            PlanFileError::InvalidReference {
+                day: 1,
+                reference,
+                error: ReferenceError::UnknownBook(book),
+            } if reference == "Hezekiah 1" && book == "Hezekiah"
+        ));
+        assert!(matches!(
+            &errors[1],
+            PlanFileError::InvalidReference {
+                day: 3,
+                error: ReferenceError::ChapterNotFound {
+                    book: BibleEnum::Psalms,
+                    chapter: 151,
+                },
+                ..
+            }
+        ));
+        assert_eq!(
+            errors[1].to_string(),
+            "Day 3, \"Psalms 151\": Psalms has no chapter 151"
+        );
+        assert!(matches!(
+            definition.to_plan(start()),
+            Err(PlanFileError::InvalidReference { day: 1, .. })
+        ));
+
+        let empty = PlanDefinition {
+            days: Vec::new(),
+            ..definition
+        };
+        assert!(matches!(empty.errors()[..], [PlanFileError::NoDay]));
+    }
+
+    #[test]
+    fn test_load() {
+        let directory =
+            std::env::temp_dir().join(format!("bible_read_test_plan_{}", std::process::id()));
+        std::fs::create_dir_all(&directory).unwrap();
+        std::fs::write(directory.join("lent.ron"), LENT).unwrap();
+        std::fs::write(directory.join("john.csv"), "1,John 1").unwrap();
+
+        assert_eq!(
+            PlanDefinition::load(&directory.join("lent.ron"))
+                .unwrap()
+                .days
+                .len(),
+            3
+        );
+        assert_eq!(
+            PlanDefinition::load(&directory.join("john.csv"))
+                .unwrap()
+                .name,
+            "john"
+        );
+        assert!(matches!(
+            PlanDefinition::load(&directory.join("lent.txt")),
+            Err(PlanFileError::UnknownFormat(_))
+        ));
+        assert!(matches!(
+            PlanDefinition::load(&directory.join("missing.toml")),
+            Err(PlanFileError::Io { .. })
+        ));
+
+        std::fs::remove_dir_all(&directory).unwrap();
+    }
+}
@@ -0,0 +1,521 @@
+use bible::{
+    bible_enum::BibleEnum::{self, *},
+    canon::Canon,
+    metadata::Testament,
+    passage::{Passage, PassageError},
+    verse_ref::VerseRef,
+    Bible, BIBLE,
+};
+use book::book_components::{chapter_number::ChapterNumber, verse::Verse};
+use chrono::{Datelike, NaiveDate};
+use serde::{Deserialize, Serialize};
+
+pub mod custom;
+
+#[derive(Debug, thiserror::Error, PartialEq)]
+pub enum PlanError {
+    #[error("{0:?} is not in the Bible of the plan")]
+    BookNotInBible(BibleEnum),
+    #[error(transparent)]
+    Passage(#[from] PassageError),
+}
+
+/// The plans built from the chapters of a Bible
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum BuiltinPlan {
+    /// The books in the order of the Bible, in a year
+    Canonical,
+    /// The New Testament in 90 days
+    NewTestament,
+    /// The books in the order of their events, in a year
+    Chronological,
+    /// Four chapters a day, as the calendar of Robert Murray M'Cheyne
+    Mcheyne,
+    /// The Psalms and the Proverbs, each month
+    PsalmsProverbs,
+}
+
+/// When the days of a plan are read
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum Schedule {
+    /// A day after the other from the start date, until the last day
+    #[default]
+    Once,
+    /// The day of the month, every month from the start date
+    Monthly,
+}
+
+/// The passages to read each day
+#[derive(Debug, Clone, PartialEq)]
+pub struct ReadingPlan {
+    name: String,
+    start: NaiveDate,
+    schedule: Schedule,
+    days: Vec<Vec<Passage>>,
+}
+
+/// A chapter of a track, weighed by its verses
+#[derive(Debug, Clone, Copy)]
+struct TrackChapter {
+    book: BibleEnum,
+    chapter: ChapterNumber,
+    last_verse: Verse,
+}
+
+impl BuiltinPlan {
+    pub const ALL: [BuiltinPlan; 5] = [
+        BuiltinPlan::Canonical,
+        BuiltinPlan::NewTestament,
+        BuiltinPlan::Chronological,
+        BuiltinPlan::Mcheyne,
+        BuiltinPlan::PsalmsProverbs,
+    ];
+
+    /// Build the plan from the chapters of `BIBLE`
+    pub fn build(&self, start: NaiveDate) -> Result<ReadingPlan, PlanError> {
+        self.build_in(start, &BIBLE)
+    }
+
+    /// Build the plan from the chapters of a Bible, each day of a track
+    /// having about the same number of verses
+    pub fn build_in(&self, start: NaiveDate, bible: &Bible) -> Result<ReadingPlan, PlanError> {
+        let (day_count, schedule): (usize, Schedule) = match self {
+            BuiltinPlan::NewTestament => (90, Schedule::Once),
+            BuiltinPlan::PsalmsProverbs => (31, Schedule::Monthly),
+            _ => (365, Schedule::Once),
+        };
+
+        let mut days: Vec<Vec<Passage>> = vec![Vec::new(); day_count];
+
+        for track in self.tracks() {
+            let chapters: Vec<TrackChapter> = track_chapters(&track, bible)?;
+
+            for (day, day_chapters) in days.iter_mut().zip(split(&chapters, day_count)) {
+                day.extend(to_passages(day_chapters, bible)?);
+            }
+        }
+
+        Ok(ReadingPlan::new(self.to_string(), start, days).with_schedule(schedule))
+    }
+
+    /// The books of each track read in parallel
+    fn tracks(&self) -> Vec<Vec<BibleEnum>> {
+        let protestant: &[BibleEnum] = Canon::Protestant.books();
+        let books = |first: BibleEnum, last: BibleEnum| -> Vec<BibleEnum> {
+            let position = |book: BibleEnum| protestant.iter().position(|item| *item == book);
+
+            match (position(first), position(last)) {
+                (Some(first), Some(last)) => protestant[first..=last].to_vec(),
+                _ => Vec::new(),
+            }
+        };
+
+        match self {
+            BuiltinPlan::Canonical => vec![protestant.to_vec()],
+            BuiltinPlan::NewTestament => vec![protestant
+                .iter()
+                .copied()
+                .filter(|book| book.testament() == Testament::New)
+                .collect()],
+            BuiltinPlan::Chronological => vec![CHRONOLOGICAL_BOOKS.to_vec()],
+            // The tracks start at Genesis 1, Matthew 1, Ezra 1 and Acts 1, the
+            // New Testament and the Psalms being read twice
+            BuiltinPlan::Mcheyne => vec![
+                books(Genesis, SecondChronicles),
+                books(Matthew, Revelation),
+                books(Ezra, Malachi),
+                [books(Acts, Revelation), vec![Psalms]].concat(),
+            ],
+            BuiltinPlan::PsalmsProverbs => vec![vec![Psalms], vec![Proverbs]],
+        }
+    }
+}
+
+#[derive(Debug, thiserror::Error, PartialEq)]
+#[error("This reading plan is unknown: {0}")]
+pub struct BuiltinPlanError(String);
+
+impl std::str::FromStr for BuiltinPlan {
+    type Err = BuiltinPlanError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "canonical" => Ok(BuiltinPlan::Canonical),
+            "new-testament" => Ok(BuiltinPlan::NewTestament),
+            "chronological" => Ok(BuiltinPlan::Chronological),
+            "mcheyne" => Ok(BuiltinPlan::Mcheyne),
+            "psalms-proverbs" => Ok(BuiltinPlan::PsalmsProverbs),
+            _ => Err(BuiltinPlanError(s.to_string())),
+        }
+    }
+}
+
+impl std::fmt::Display for BuiltinPlan {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            BuiltinPlan::Canonical => "canonical",
+            BuiltinPlan::NewTestament => "new-testament",
+            BuiltinPlan::Chronological => "chronological",
+            BuiltinPlan::Mcheyne => "mcheyne",
+            BuiltinPlan::PsalmsProverbs => "psalms-proverbs",
+        })
+    }
+}
+
+impl ReadingPlan {
+    /// Create a plan whose days are read one after the other from a date
+    pub fn new(name: impl Into<String>, start: NaiveDate, days: Vec<Vec<Passage>>) -> ReadingPlan {
+        ReadingPlan {
+            name: name.into(),
+            start,
+            schedule: Schedule::default(),
+            days,
+        }
+    }
+
+    pub fn with_schedule(mut self, schedule: Schedule) -> ReadingPlan {
+        self.schedule = schedule;
+        self
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn start(&self) -> NaiveDate {
+        self.start
+    }
+
+    pub fn schedule(&self) -> Schedule {
+        self.schedule
+    }
+
+    /// The passages of every day, from the first
+    pub fn days(&self) -> &[Vec<Passage>] {
+        &self.days
+    }
+
+    /// The passages of a day, the first being 1
+    pub fn day(&self, number: usize) -> Option<&[Passage]> {
+        number
+            .checked_sub(1)
+            .and_then(|index| self.days.get(index))
+            .map(Vec::as_slice)
+    }
+
+    /// The number of the day to read at a date, None before the start or
+    /// after the last day
+    pub fn day_number_on(&self, date: NaiveDate) -> Option<usize> {
+        if date < self.start {
+            return None;
+        }
+
+        let number: usize = match self.schedule {
+            Schedule::Once => (date - self.start).num_days() as usize + 1,
+            Schedule::Monthly => date.day() as usize,
+        };
+
+        (number <= self.days.len()).then_some(number)
+    }
+
+    /// The number and the passages of the day to read at a date
+    pub fn reading_on(&self, date: NaiveDate) -> Option<(usize, &[Passage])> {
+        let number: usize = self.day_number_on(date)?;
+
+        Some((number, self.day(number)?))
+    }
+}
+
+/// The books in the order of their events, the prophets and the epistles
+/// among the histories they belong to
+const CHRONOLOGICAL_BOOKS: &[BibleEnum] = &[
+    Genesis,
+    Job,
+    Exodus,
+    Leviticus,
+    Numbers,
+    Deuteronomy,
+    Joshua,
+    Judges,
+    Ruth,
+    FirstSamuel,
+    SecondSamuel,
+    FirstChronicles,
+    Psalms,
+    FirstKings,
+    Proverbs,
+    Ecclesiastes,
+    SongOfSolomon,
+    SecondKings,
+    SecondChronicles,
+    Obadiah,
+    Joel,
+    Jonah,
+    Amos,
+    Hosea,
+    Isaiah,
+    Micah,
+    Nahum,
+    Zephaniah,
+    Habakkuk,
+    Jeremiah,
+    Lamentations,
+    Ezekiel,
+    Daniel,
+    Ezra,
+    Haggai,
+    Zechariah,
+    Esther,
+    Nehemiah,
+    Malachi,
+    Matthew,
+    Mark,
+    Luke,
+    John,
+    Acts,
+    James,
+    Galatians,
+    FirstThessalonians,
+    SecondThessalonians,
+    FirstCorinthians,
+    SecondCorinthians,
+    Romans,
+    Ephesians,
+    Philippians,
+    Colossians,
+    Philemon,
+    FirstTimothy,
+    Titus,
+    FirstPeter,
+    Hebrews,
+    SecondTimothy,
+    SecondPeter,
+    Jude,
+    FirstJohn,
+    SecondJohn,
+    ThirdJohn,
+    Revelation,
+];
+
+fn track_chapters(books: &[BibleEnum], bible: &Bible) -> Result<Vec<TrackChapter>, PlanError> {
+    let mut chapters: Vec<TrackChapter> = Vec::new();
+
+    for book in books {
+        let book_value = bible.get(*book).ok_or(PlanError::BookNotInBible(*book))?;
+
+        for (number, chapter) in book_value.chapters.range(..) {
+            chapters.push(TrackChapter {
+                book: *book,
+                chapter: *number,
+                last_verse: *chapter.get_max_verse(),
+            });
+        }
+    }
+
+    Ok(chapters)
+}
+
+/// Share whole chapters between days, each day ending at the chapter which
+/// brings its total of verses closest to an even share, and having at least a
+/// chapter if there are as many chapters as days
+fn split(chapters: &[TrackChapter], day_count: usize) -> Vec<&[TrackChapter]> {
+    let weights: Vec<u64> = chapters
+        .iter()
+        .map(|chapter| u64::from(u8::from(chapter.last_verse)))
+        .collect();
+    let total: u64 = weights.iter().sum();
+
+    let every_day: bool = chapters.len() >= day_count;
+
+    let mut days: Vec<&[TrackChapter]> = Vec::with_capacity(day_count);
+    let mut start: usize = 0;
+    let mut read: u64 = 0;
+
+    for day in 0..day_count {
+        let target: u64 = total * (day as u64 + 1) / day_count as u64;
+        let max_end: usize = if every_day {
+            // Leave a chapter to each following day
+            (chapters.len() + day + 1 - day_count).max(start)
+        } else {
+            chapters.len()
+        };
+        let mut end: usize = start;
+
+        while end < max_end {
+            let with_next: u64 = read + weights[end];
+            let is_required: bool = every_day && end == start;
+            if !is_required && with_next.abs_diff(target) >= read.abs_diff(target) {
+                break;
+            }
+            read = with_next;
+            end += 1;
+        }
+
+        days.push(&chapters[start..end]);
+        start = end;
+    }
+
+    days
+}
+
+/// The passages of following chapters, one by book
+fn to_passages(chapters: &[TrackChapter], bible: &Bible) -> Result<Vec<Passage>, PlanError> {
+    let first_verse = Verse::try_from(1u8).unwrap();
+
+    chapters
+        .chunk_by(|first, second| first.book == second.book)
+        .map(|book_chapters| {
+            let first: &TrackChapter = &book_chapters[0];
+            let last: &TrackChapter = &book_chapters[book_chapters.len() - 1];
+
+            Ok(Passage::new_in(
+                VerseRef::new(first.book, first.chapter, first_verse),
+                VerseRef::new(last.book, last.chapter, last.last_verse),
+                bible,
+            )?)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn start() -> NaiveDate {
+        NaiveDate::from_ymd_opt(2025, 1, 1).unwrap()
+    }
+
+    fn verse_count(plan: &ReadingPlan) -> usize {
+        plan.days()
+            .iter()
+            .flatten()
+            .map(|passage| passage.verse_count())
+            .sum()
+    }
+
+    fn first_verses(passages: &[Passage]) -> Vec<String> {
+        passages
+            .iter()
+            .map(|passage| passage.start().to_string())
+            .collect()
+    }
+
+    #[test]
+    fn test_canonical() {
+        let plan = BuiltinPlan::Canonical.build(start()).unwrap();
+
+        assert_eq!(plan.name(), "canonical");
+        assert_eq!(plan.days().len(), 365);
+        assert!(plan.days().iter().all(|day| !day.is_empty()));
+        assert_eq!(verse_count(&plan), 31102);
+        assert_eq!(first_verses(plan.day(1).unwrap()), vec!["Genesis 1:1"]);
+        assert_eq!(
+            plan.day(365).unwrap().last().unwrap().end().to_string(),
+            "Revelation 22:21"
+        );
+
+        // The days have about the same number of verses
+        let average: usize = 31102 / 365;
+        for day in plan.days() {
+            let count: usize = day.iter().map(|passage| passage.verse_count()).sum();
+            assert!(count < average * 3, "{count} verses in {day:?}");
+        }
+    }
+
+    #[test]
+    fn test_new_testament() {
+        let plan = BuiltinPlan::NewTestament.build(start()).unwrap();
+
+        assert_eq!(plan.days().len(), 90);
+        assert_eq!(verse_count(&plan), 7957);
+        assert_eq!(first_verses(plan.day(1).unwrap()), vec!["Matthew 1:1"]);
+    }
+
+    #[test]
+    fn test_chronological() {
+        let mut books: Vec<BibleEnum> = CHRONOLOGICAL_BOOKS.to_vec();
+        books.sort();
+        assert_eq!(books, Canon::Protestant.books());
+
+        let plan = BuiltinPlan::Chronological.build(start()).unwrap();
+        assert_eq!(verse_count(&plan), 31102);
+        assert!(plan.days()[..20]
+            .iter()
+            .flatten()
+            .any(|passage| passage.start().book == Job));
+    }
+
+    #[test]
+    fn test_mcheyne() {
+        let plan = BuiltinPlan::Mcheyne.build(start()).unwrap();
+
+        assert_eq!(plan.days().len(), 365);
+        assert_eq!(
+            first_verses(plan.day(1).unwrap()),
+            vec!["Genesis 1:1", "Matthew 1:1", "Ezra 1:1", "Acts 1:1"]
+        );
+        assert_eq!(
+            plan.day(365)
+                .unwrap()
+                .iter()
+                .map(|passage| passage.end().to_string())
+                .collect::<Vec<_>>(),
+            vec![
+                "2 Chronicles 36:23",
+                "Revelation 22:21",
+                "Malachi 4:6",
+                "Psalms 150:6"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_psalms_proverbs() {
+        let plan = BuiltinPlan::PsalmsProverbs.build(start()).unwrap();
+
+        assert_eq!(plan.schedule(), Schedule::Monthly);
+        assert_eq!(plan.days().len(), 31);
+        assert!(plan
+            .days()
+            .iter()
+            .all(|day| day.iter().any(|passage| passage.start().book == Psalms)));
+
+        let march_31 = NaiveDate::from_ymd_opt(2025, 3, 31).unwrap();
+        let (number, passages) = plan.reading_on(march_31).unwrap();
+        assert_eq!(number, 31);
+        assert_eq!(passages.last().unwrap().to_string(), "Proverbs 31:1–31");
+    }
+
+    #[test]
+    fn test_day_number_on() {
+        let plan = BuiltinPlan::NewTestament.build(start()).unwrap();
+
+        assert_eq!(plan.day_number_on(start()), Some(1));
+        assert_eq!(
+            plan.day_number_on(NaiveDate::from_ymd_opt(2024, 12, 31).unwrap()),
+            None
+        );
+        assert_eq!(
+            plan.day_number_on(NaiveDate::from_ymd_opt(2025, 3, 31).unwrap()),
+            Some(90)
+        );
+        assert_eq!(
+            plan.day_number_on(NaiveDate::from_ymd_opt(2025, 4, 1).unwrap()),
+            None
+        );
+        assert_eq!(plan.day(0), None);
+    }
+
+    #[test]
+    fn test_from_str() {
+        for plan in BuiltinPlan::ALL {
+            assert_eq!(plan.to_string().parse(), Ok(plan));
+        }
+
+        assert_eq!(
+            "yearly".parse::<BuiltinPlan>(),
+            Err(BuiltinPlanError("yearly".to_string()))
+        );
+    }
+}
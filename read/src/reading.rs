@@ -1,17 +1,48 @@
-use bible::{bible_enum::BibleEnum, BIBLE};
+use std::ops::Bound;
+
+use bible::{
+    bible_enum::BibleEnum,
+    canon::Canon,
+    citation::CitationFormatter,
+    locale::{Locale, LocalizedDisplay},
+    verse_ref::VerseRef,
+    versification::{Versification, VersificationError},
+    Bible, BibleError, BIBLE,
+};
 use book::{
     book_components::{chapter::Chapter, chapter_number::ChapterNumber, verse::Verse},
     Book,
 };
 use serde::{Deserialize, Serialize};
-use strum::IntoEnumIterator;
+
+use crate::boundary::BoundaryPolicy;
 
 #[derive(Debug, thiserror::Error, PartialEq)]
 pub enum ReadingError {
+    #[error("This book is not in the canon of the reading")]
+    BookNotInCanon,
     #[error("This chapter is not listed in the book")]
     ChapterNotInBook,
     #[error("This verse cannot be in this chapter")]
     VerseNotInChapter,
+    #[error("The end of the Bible is reached")]
+    EndOfBible,
+    #[error("The start of the Bible is reached")]
+    StartOfBible,
+    #[error(transparent)]
+    Versification(#[from] VersificationError),
+    #[error(transparent)]
+    Bible(BibleError),
+}
+
+impl From<BibleError> for ReadingError {
+    fn from(error: BibleError) -> Self {
+        match error {
+            BibleError::ChapterNotFound => ReadingError::ChapterNotInBook,
+            BibleError::VerseNotFound => ReadingError::VerseNotInChapter,
+            error => ReadingError::Bible(error),
+        }
+    }
 }
 
 #[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
@@ -19,17 +50,64 @@ pub struct Reading {
     current_book: BibleEnum,
     current_chapter: ChapterNumber,
     current_verse: Verse,
+    #[serde(default)]
+    canon: Canon,
+    #[serde(default)]
+    boundary_policy: BoundaryPolicy,
+    /// The times the reading went past the end of the canon to start over
+    #[serde(default)]
+    cycles: u32,
+}
+
+/// A step from a chapter to the next or the previous one
+#[derive(Debug, Clone, Copy)]
+struct Step {
+    book: BibleEnum,
+    chapter: ChapterNumber,
+    /// Whether the step went around the canon
+    wrapped: bool,
+}
+
+impl Step {
+    fn to(book: BibleEnum, chapter: ChapterNumber) -> Step {
+        Step {
+            book,
+            chapter,
+            wrapped: false,
+        }
+    }
 }
 
 impl std::fmt::Display for Reading {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(
-            f,
-            "{} {}:{}",
-            BIBLE[self.current_book].name,
-            u8::from(self.current_chapter),
-            u8::from(self.current_verse)
-        )
+        self.display_in(&BIBLE).fmt(f)
+    }
+}
+
+/// A reading shown with the book names of a Bible
+pub struct ReadingInBible<'a> {
+    reading: &'a Reading,
+    bible: &'a Bible,
+}
+
+impl std::fmt::Display for ReadingInBible<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.bible.get(self.reading.current_book) {
+            Some(book) => write!(
+                f,
+                "{} {}:{}",
+                book.name,
+                u8::from(self.reading.current_chapter),
+                u8::from(self.reading.current_verse)
+            ),
+            None => self.reading.to_verse_ref().fmt(f),
+        }
+    }
+}
+
+impl LocalizedDisplay for Reading {
+    fn fmt_localized(&self, locale: &Locale, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.to_verse_ref().fmt_localized(locale, f)
     }
 }
 
@@ -39,15 +117,87 @@ impl Reading {
         chapter: ChapterNumber,
         verse: Verse,
     ) -> Result<Reading, ReadingError> {
-        Self::validate_fields(&book, &chapter, &verse)?;
+        Self::new_in(book, chapter, verse, &BIBLE)
+    }
+
+    /// Create a reading checked against a Bible
+    pub fn new_in(
+        book: BibleEnum,
+        chapter: ChapterNumber,
+        verse: Verse,
+        bible: &Bible,
+    ) -> Result<Reading, ReadingError> {
+        Self::with_canon_in(Canon::default(), book, chapter, verse, bible)
+    }
+
+    /// Create a reading whose books are the books of a canon
+    pub fn with_canon(
+        canon: Canon,
+        book: BibleEnum,
+        chapter: ChapterNumber,
+        verse: Verse,
+    ) -> Result<Reading, ReadingError> {
+        Self::with_canon_in(canon, book, chapter, verse, &BIBLE)
+    }
+
+    /// Create a reading whose books are the books of a canon, checked against
+    /// a Bible
+    pub fn with_canon_in(
+        canon: Canon,
+        book: BibleEnum,
+        chapter: ChapterNumber,
+        verse: Verse,
+        bible: &Bible,
+    ) -> Result<Reading, ReadingError> {
+        Self::validate_fields(bible, &canon, &book, &chapter, &verse)?;
 
         Ok(Reading {
             current_book: book,
             current_chapter: chapter,
             current_verse: verse,
+            canon,
+            boundary_policy: BoundaryPolicy::default(),
+            cycles: 0,
         })
     }
 
+    /// What navigating does beyond the first or the last book of the canon
+    pub fn boundary_policy(&self) -> BoundaryPolicy {
+        self.boundary_policy
+    }
+
+    pub fn set_boundary_policy(&mut self, boundary_policy: BoundaryPolicy) {
+        self.boundary_policy = boundary_policy;
+    }
+
+    /// The times the reading went past the end of the canon to start over,
+    /// less the times it went back before its start
+    pub fn cycles(&self) -> u32 {
+        self.cycles
+    }
+
+    pub fn canon(&self) -> &Canon {
+        &self.canon
+    }
+
+    pub fn set_canon(&mut self, canon: Canon) -> Result<(), ReadingError> {
+        self.set_canon_in(canon, &BIBLE)
+    }
+
+    pub fn set_canon_in(&mut self, canon: Canon, bible: &Bible) -> Result<(), ReadingError> {
+        Self::validate_fields(
+            bible,
+            &canon,
+            &self.current_book,
+            &self.current_chapter,
+            &self.current_verse,
+        )?;
+
+        self.canon = canon;
+
+        Ok(())
+    }
+
     pub fn current_book(&self) -> &BibleEnum {
         &self.current_book
     }
@@ -60,25 +210,99 @@ impl Reading {
         &self.current_verse
     }
 
+    pub fn to_verse_ref(&self) -> VerseRef {
+        VerseRef::new(self.current_book, self.current_chapter, self.current_verse)
+    }
+
+    /// Check that the position of the reading is in a Bible, like a saved
+    /// reading against the versification of another translation
+    pub fn validate_in(&self, bible: &Bible) -> Result<(), ReadingError> {
+        Self::validate_fields(
+            bible,
+            &self.canon,
+            &self.current_book,
+            &self.current_chapter,
+            &self.current_verse,
+        )
+    }
+
+    /// Show the reading with the book names of a Bible
+    pub fn display_in<'a>(&'a self, bible: &'a Bible) -> ReadingInBible<'a> {
+        ReadingInBible {
+            reading: self,
+            bible,
+        }
+    }
+
+    /// Write the position of the reading with a citation formatter
+    pub fn cite(&self, formatter: &CitationFormatter) -> String {
+        formatter.format_verse(&self.to_verse_ref())
+    }
+
+    /// The position of the reading in another versification
+    pub fn in_versification(&self, versification: Versification) -> Result<VerseRef, ReadingError> {
+        Ok(Versification::Kjv.map_to(versification, self.to_verse_ref())?)
+    }
+
+    /// Create a reading from a verse numbered in another versification
+    pub fn from_versification(
+        versification: Versification,
+        verse: VerseRef,
+    ) -> Result<Reading, ReadingError> {
+        Self::from_versification_in(versification, verse, &BIBLE)
+    }
+
+    pub fn from_versification_in(
+        versification: Versification,
+        verse: VerseRef,
+        bible: &Bible,
+    ) -> Result<Reading, ReadingError> {
+        let verse: VerseRef = versification.map_to(Versification::Kjv, verse)?;
+
+        Reading::new_in(verse.book, verse.chapter, verse.verse, bible)
+    }
+
     pub fn modify_reading(
         &mut self,
         book: BibleEnum,
         chapter: ChapterNumber,
         verse: Verse,
     ) -> Result<(), ReadingError> {
-        Self::validate_fields(&book, &chapter, &verse)?;
+        self.modify_reading_in(book, chapter, verse, &BIBLE)
+    }
 
-        *self = Reading {
-            current_book: book,
-            current_chapter: chapter,
-            current_verse: verse,
-        };
+    pub fn modify_reading_in(
+        &mut self,
+        book: BibleEnum,
+        chapter: ChapterNumber,
+        verse: Verse,
+        bible: &Bible,
+    ) -> Result<(), ReadingError> {
+        Self::validate_fields(bible, &self.canon, &book, &chapter, &verse)?;
+
+        self.current_book = book;
+        self.current_chapter = chapter;
+        self.current_verse = verse;
 
         Ok(())
     }
 
     pub fn set_current_book(&mut self, book: BibleEnum) -> Result<(), ReadingError> {
-        Self::validate_fields(&book, &self.current_chapter, &self.current_verse)?;
+        self.set_current_book_in(book, &BIBLE)
+    }
+
+    pub fn set_current_book_in(
+        &mut self,
+        book: BibleEnum,
+        bible: &Bible,
+    ) -> Result<(), ReadingError> {
+        Self::validate_fields(
+            bible,
+            &self.canon,
+            &book,
+            &self.current_chapter,
+            &self.current_verse,
+        )?;
 
         self.current_book = book;
 
@@ -86,7 +310,21 @@ impl Reading {
     }
 
     pub fn set_current_chapter(&mut self, chapter: ChapterNumber) -> Result<(), ReadingError> {
-        Self::validate_fields(&self.current_book, &chapter, &self.current_verse)?;
+        self.set_current_chapter_in(chapter, &BIBLE)
+    }
+
+    pub fn set_current_chapter_in(
+        &mut self,
+        chapter: ChapterNumber,
+        bible: &Bible,
+    ) -> Result<(), ReadingError> {
+        Self::validate_fields(
+            bible,
+            &self.canon,
+            &self.current_book,
+            &chapter,
+            &self.current_verse,
+        )?;
 
         self.current_chapter = chapter;
 
@@ -94,138 +332,480 @@ impl Reading {
     }
 
     pub fn set_current_verse(&mut self, verse: Verse) -> Result<(), ReadingError> {
-        let current_book: Book = BIBLE[self.current_book].clone();
-        let chapter: &Chapter = current_book.chapters.get(self.current_chapter).unwrap();
+        self.set_current_verse_in(verse, &BIBLE)
+    }
 
-        Self::validate_verse(chapter, &verse)?;
+    pub fn set_current_verse_in(
+        &mut self,
+        verse: Verse,
+        bible: &Bible,
+    ) -> Result<(), ReadingError> {
+        Self::validate_verse(
+            Self::chapter(bible, &self.current_book, &self.current_chapter)?,
+            &verse,
+        )?;
 
         self.current_verse = verse;
 
         Ok(())
     }
 
-    pub fn next_book(&mut self, count: &u8) -> Result<(), ReadingError> {
-        let new_book = match BibleEnum::iter()
-            .skip_while(|&value| value < self.current_book)
-            .nth(*count as usize)
-        {
-            Some(book) => book,
-            None => BibleEnum::Genesis,
-        };
-        self.modify_reading(new_book, 1u8.try_into().unwrap(), 1u8.try_into().unwrap())?;
+    pub fn next_book(&mut self, count: &u32) -> Result<(), ReadingError> {
+        self.next_book_in(count, &BIBLE)
+    }
+
+    /// Go to the first verse of a following book, past the last book of the
+    /// canon following the boundary policy
+    pub fn next_book_in(&mut self, count: &u32, bible: &Bible) -> Result<(), ReadingError> {
+        if *count == 0 {
+            return Ok(());
+        }
+
+        let books: &[BibleEnum] = self.canon.books();
+        let (position, cycles) = self.forward(self.position()?, books.len(), *count)?;
+
+        self.go_to_book(books[position], bible)?;
+        self.cycles = self.cycles.saturating_add(cycles);
 
         Ok(())
     }
 
-    pub fn next_chapter(&mut self, count: &u8) -> Result<(), ReadingError> {
-        let book: Book = BIBLE[self.current_book].clone();
-        let last_chapter: ChapterNumber = *book
-            .chapters
-            .into_iter()
-            .last()
-            .unwrap()
-            .clone()
-            .get_chapter_number();
+    pub fn next_chapter(&mut self, count: &u32) -> Result<(), ReadingError> {
+        self.next_chapter_in(count, &BIBLE)
+    }
 
-        if let Some((new_chapter_number, _)) = book
-            .chapters
-            .range(self.current_chapter..last_chapter)
-            .nth(*count as usize)
-        {
-            self.modify_reading(
-                self.current_book,
-                *new_chapter_number,
-                1u8.try_into().unwrap(),
-            )?;
-        } else {
-            self.next_book(&1u8)?;
+    /// Go to the first verse of a following chapter, carrying over into the
+    /// following books
+    pub fn next_chapter_in(&mut self, count: &u32, bible: &Bible) -> Result<(), ReadingError> {
+        if *count == 0 {
+            return Ok(());
+        }
+
+        let (remaining, mut cycles) = self.cycle_count(*count, || self.chapter_total(bible));
+        let (mut book, mut chapter) = (self.current_book, self.current_chapter);
+
+        for _ in 0..remaining {
+            let Some(step) = self.following_chapter(bible, book, chapter)? else {
+                break;
+            };
+            (book, chapter) = (step.book, step.chapter);
+            cycles += step.wrapped as u32;
         }
 
+        self.modify_reading_in(book, chapter, 1u8.try_into().unwrap(), bible)?;
+        self.cycles = self.cycles.saturating_add(cycles);
+
         Ok(())
     }
 
-    pub fn next_verse(&mut self, count: &u8) -> Result<(), ReadingError> {
-        let book: Book = BIBLE[self.current_book].clone();
-        let max_chapter: Verse = *book.chapters[self.current_chapter].get_max_verse();
+    pub fn next_verse(&mut self, count: &u32) -> Result<(), ReadingError> {
+        self.next_verse_in(count, &BIBLE)
+    }
 
-        if u8::from(self.current_verse) + *count <= u8::from(max_chapter) {
-            self.modify_reading(
-                self.current_book,
-                self.current_chapter,
-                (u8::from(self.current_verse) + *count).try_into().unwrap(),
-            )?;
-        } else {
-            self.next_chapter(&1u8)?;
+    /// Go to a following verse, carrying over into the following chapters and
+    /// books
+    pub fn next_verse_in(&mut self, count: &u32, bible: &Bible) -> Result<(), ReadingError> {
+        if *count == 0 {
+            return Ok(());
         }
 
+        let (mut remaining, mut cycles) = self.cycle_count(*count, || self.verse_total(bible));
+        let (mut book, mut chapter) = (self.current_book, self.current_chapter);
+        let mut verse: u32 = u8::from(self.current_verse) as u32;
+
+        loop {
+            let max_verse: u32 =
+                u8::from(Self::chapter(bible, &book, &chapter)?.get_max_verse()) as u32;
+
+            if verse + remaining <= max_verse {
+                verse += remaining;
+                break;
+            }
+
+            let Some(step) = self.following_chapter(bible, book, chapter)? else {
+                verse = max_verse;
+                break;
+            };
+
+            // The verses left in the chapter, then the first verse of the next
+            remaining -= max_verse - verse + 1;
+            (book, chapter, verse) = (step.book, step.chapter, 1);
+            cycles += step.wrapped as u32;
+        }
+
+        self.modify_reading_in(book, chapter, Self::to_verse(verse), bible)?;
+        self.cycles = self.cycles.saturating_add(cycles);
+
         Ok(())
     }
 
-    pub fn previous_book(&mut self, count: &u8) -> Result<(), ReadingError> {
-        let new_book = match BibleEnum::iter()
-            .rev()
-            .skip_while(|&value| value > self.current_book)
-            .nth(*count as usize)
-        {
-            Some(book) => book,
-            None => BibleEnum::Revelation,
-        };
+    pub fn previous_book(&mut self, count: &u32) -> Result<(), ReadingError> {
+        self.previous_book_in(count, &BIBLE)
+    }
 
-        self.modify_reading(new_book, 1u8.try_into().unwrap(), 1u8.try_into().unwrap())?;
+    /// Go to the first verse of a preceding book, before the first book of the
+    /// canon following the boundary policy
+    pub fn previous_book_in(&mut self, count: &u32, bible: &Bible) -> Result<(), ReadingError> {
+        if *count == 0 {
+            return Ok(());
+        }
+
+        let books: &[BibleEnum] = self.canon.books();
+        let (position, cycles) = self.backward(self.position()?, books.len(), *count)?;
+
+        self.go_to_book(books[position], bible)?;
+        self.cycles = self.cycles.saturating_sub(cycles);
 
         Ok(())
     }
 
-    pub fn previous_chapter(&mut self, count: &u8) -> Result<(), ReadingError> {
-        let book: Book = BIBLE[self.current_book].clone();
+    pub fn previous_chapter(&mut self, count: &u32) -> Result<(), ReadingError> {
+        self.previous_chapter_in(count, &BIBLE)
+    }
 
-        if let Some((new_chapter_number, _)) = book
-            .chapters
-            .range(1u8.try_into().unwrap()..self.current_chapter)
-            .nth_back((*count - 1) as usize)
-        {
-            self.modify_reading(
-                self.current_book,
-                *new_chapter_number,
-                1u8.try_into().unwrap(),
-            )?;
-        } else {
-            self.previous_book(&1u8)?;
+    /// Go to the first verse of a preceding chapter, carrying over into the
+    /// preceding books
+    pub fn previous_chapter_in(&mut self, count: &u32, bible: &Bible) -> Result<(), ReadingError> {
+        if *count == 0 {
+            return Ok(());
+        }
+
+        let (remaining, mut cycles) = self.cycle_count(*count, || self.chapter_total(bible));
+        let (mut book, mut chapter) = (self.current_book, self.current_chapter);
+
+        for _ in 0..remaining {
+            let Some(step) = self.preceding_chapter(bible, book, chapter)? else {
+                break;
+            };
+            (book, chapter) = (step.book, step.chapter);
+            cycles += step.wrapped as u32;
         }
 
+        self.modify_reading_in(book, chapter, 1u8.try_into().unwrap(), bible)?;
+        self.cycles = self.cycles.saturating_sub(cycles);
+
         Ok(())
     }
 
-    pub fn previous_verse(&mut self, count: &u8) -> Result<(), ReadingError> {
-        let value_verse: i16 = u8::from(self.current_verse) as i16;
+    pub fn previous_verse(&mut self, count: &u32) -> Result<(), ReadingError> {
+        self.previous_verse_in(count, &BIBLE)
+    }
 
-        let substract: i16 = value_verse - (*count as i16);
+    /// Go to a preceding verse, carrying over into the preceding chapters and
+    /// books
+    pub fn previous_verse_in(&mut self, count: &u32, bible: &Bible) -> Result<(), ReadingError> {
+        if *count == 0 {
+            return Ok(());
+        }
 
-        if substract > 0i16 {
-            self.modify_reading(
-                self.current_book,
-                self.current_chapter,
-                (substract as u8).try_into().unwrap(),
-            )?;
-        } else {
-            self.previous_chapter(&1u8)?;
+        let (mut remaining, mut cycles) = self.cycle_count(*count, || self.verse_total(bible));
+        let (mut book, mut chapter) = (self.current_book, self.current_chapter);
+        let mut verse: u32 = u8::from(self.current_verse) as u32;
+
+        while remaining >= verse {
+            let Some(step) = self.preceding_chapter(bible, book, chapter)? else {
+                remaining = verse - 1;
+                break;
+            };
+
+            // The verses before in the chapter, then the last verse of the
+            // previous one
+            remaining -= verse;
+            (book, chapter) = (step.book, step.chapter);
+            verse = u8::from(Self::chapter(bible, &book, &chapter)?.get_max_verse()) as u32;
+            cycles += step.wrapped as u32;
         }
 
+        self.modify_reading_in(book, chapter, Self::to_verse(verse - remaining), bible)?;
+        self.cycles = self.cycles.saturating_sub(cycles);
+
         Ok(())
     }
 
+    /// Go to the first book of a following section of the canon
+    pub fn next_section(&mut self, count: &u32) -> Result<(), ReadingError> {
+        self.next_section_in(count, &BIBLE)
+    }
+
+    pub fn next_section_in(&mut self, count: &u32, bible: &Bible) -> Result<(), ReadingError> {
+        if *count == 0 {
+            return Ok(());
+        }
+
+        let section_starts: Vec<usize> = self.section_starts();
+        let current_section: usize = self.current_section(&section_starts)?;
+        let (section, cycles) = self.forward(current_section, section_starts.len(), *count)?;
+
+        self.go_to_book(self.canon.books()[section_starts[section]], bible)?;
+        self.cycles = self.cycles.saturating_add(cycles);
+
+        Ok(())
+    }
+
+    /// Go to the first book of a preceding section of the canon
+    pub fn previous_section(&mut self, count: &u32) -> Result<(), ReadingError> {
+        self.previous_section_in(count, &BIBLE)
+    }
+
+    pub fn previous_section_in(&mut self, count: &u32, bible: &Bible) -> Result<(), ReadingError> {
+        if *count == 0 {
+            return Ok(());
+        }
+
+        let section_starts: Vec<usize> = self.section_starts();
+        let current_section: usize = self.current_section(&section_starts)?;
+        let (section, cycles) = self.backward(current_section, section_starts.len(), *count)?;
+
+        self.go_to_book(self.canon.books()[section_starts[section]], bible)?;
+        self.cycles = self.cycles.saturating_sub(cycles);
+
+        Ok(())
+    }
+
+    /// Go to the first verse of a book
+    fn go_to_book(&mut self, book: BibleEnum, bible: &Bible) -> Result<(), ReadingError> {
+        self.modify_reading_in(
+            book,
+            Self::first_chapter(bible, &book)?,
+            1u8.try_into().unwrap(),
+            bible,
+        )
+    }
+
+    /// The position of the current book in the canon
+    fn position(&self) -> Result<usize, ReadingError> {
+        self.canon
+            .position(&self.current_book)
+            .ok_or(ReadingError::BookNotInCanon)
+    }
+
+    /// The position some places after another one, among `length` places,
+    /// with the cycles completed past the last one
+    fn forward(
+        &self,
+        position: usize,
+        length: usize,
+        count: u32,
+    ) -> Result<(usize, u32), ReadingError> {
+        let target: usize = position + count as usize;
+
+        match self.boundary_policy {
+            _ if target < length => Ok((target, 0)),
+            BoundaryPolicy::Wrap => Ok((target % length, (target / length) as u32)),
+            BoundaryPolicy::Clamp => Ok((length - 1, 0)),
+            BoundaryPolicy::Error => Err(ReadingError::EndOfBible),
+        }
+    }
+
+    /// The position some places before another one, among `length` places,
+    /// with the cycles gone back past the first one
+    fn backward(
+        &self,
+        position: usize,
+        length: usize,
+        count: u32,
+    ) -> Result<(usize, u32), ReadingError> {
+        match (position.checked_sub(count as usize), self.boundary_policy) {
+            (Some(target), _) => Ok((target, 0)),
+            (None, BoundaryPolicy::Wrap) => {
+                let before: usize = count as usize - position;
+                Ok((
+                    (length - before % length) % length,
+                    before.div_ceil(length) as u32,
+                ))
+            }
+            (None, BoundaryPolicy::Clamp) => Ok((0, 0)),
+            (None, BoundaryPolicy::Error) => Err(ReadingError::StartOfBible),
+        }
+    }
+
+    /// The chapter after another one, in the next book of the canon after the
+    /// last chapter of a book, or `None` when clamped at the end of the canon
+    fn following_chapter(
+        &self,
+        bible: &Bible,
+        book: BibleEnum,
+        chapter: ChapterNumber,
+    ) -> Result<Option<Step>, ReadingError> {
+        if let Some((number, _)) = Self::book(bible, &book)?
+            .chapters
+            .range((Bound::Excluded(chapter), Bound::Unbounded))
+            .next()
+        {
+            return Ok(Some(Step::to(book, *number)));
+        }
+
+        let books: &[BibleEnum] = self.canon.books();
+        let position: usize = self
+            .canon
+            .position(&book)
+            .ok_or(ReadingError::BookNotInCanon)?;
+
+        let (new_book, wrapped) = match books.get(position + 1) {
+            Some(new_book) => (*new_book, false),
+            None => match self.boundary_policy {
+                BoundaryPolicy::Wrap => (books[0], true),
+                BoundaryPolicy::Clamp => return Ok(None),
+                BoundaryPolicy::Error => return Err(ReadingError::EndOfBible),
+            },
+        };
+
+        Ok(Some(Step {
+            book: new_book,
+            chapter: Self::first_chapter(bible, &new_book)?,
+            wrapped,
+        }))
+    }
+
+    /// The chapter before another one, in the previous book of the canon
+    /// before the first chapter of a book, or `None` when clamped at the
+    /// start of the canon
+    fn preceding_chapter(
+        &self,
+        bible: &Bible,
+        book: BibleEnum,
+        chapter: ChapterNumber,
+    ) -> Result<Option<Step>, ReadingError> {
+        if let Some((number, _)) = Self::book(bible, &book)?
+            .chapters
+            .range(..chapter)
+            .next_back()
+        {
+            return Ok(Some(Step::to(book, *number)));
+        }
+
+        let books: &[BibleEnum] = self.canon.books();
+        let position: usize = self
+            .canon
+            .position(&book)
+            .ok_or(ReadingError::BookNotInCanon)?;
+
+        let (new_book, wrapped) = match position.checked_sub(1) {
+            Some(new_position) => (books[new_position], false),
+            None => match self.boundary_policy {
+                BoundaryPolicy::Wrap => (books[books.len() - 1], true),
+                BoundaryPolicy::Clamp => return Ok(None),
+                BoundaryPolicy::Error => return Err(ReadingError::StartOfBible),
+            },
+        };
+        let last_chapter: ChapterNumber = *Self::book(bible, &new_book)?
+            .chapters
+            .last()
+            .ok_or(ReadingError::ChapterNotInBook)?
+            .get_chapter_number();
+
+        Ok(Some(Step {
+            book: new_book,
+            chapter: last_chapter,
+            wrapped,
+        }))
+    }
+
+    /// The chapters of the books of the canon in a Bible, after which
+    /// navigating comes back to the same chapter
+    fn chapter_total(&self, bible: &Bible) -> u32 {
+        self.canon
+            .books()
+            .iter()
+            .filter_map(|book| bible.get(*book))
+            .map(|book| book.chapters.chapter_count() as u32)
+            .sum()
+    }
+
+    /// The verses of the books of the canon in a Bible, after which
+    /// navigating comes back to the same verse
+    fn verse_total(&self, bible: &Bible) -> u32 {
+        self.canon
+            .books()
+            .iter()
+            .filter_map(|book| bible.get(*book))
+            .map(|book| book.chapters.verse_count())
+            .sum()
+    }
+
+    /// A count of at least one step without the whole cycles through the
+    /// canon, which come back to the same place, and the count of these
+    /// cycles. Only wrapping goes through whole cycles.
+    ///
+    /// Counts up to the length of the longest chapter or book are walked
+    /// without measuring the canon, which is the common case.
+    fn cycle_count(&self, count: u32, total: impl FnOnce() -> u32) -> (u32, u32) {
+        if count <= u8::MAX as u32 || self.boundary_policy != BoundaryPolicy::Wrap {
+            return (count, 0);
+        }
+
+        match total() {
+            0 => (count, 0),
+            total => ((count - 1) % total + 1, (count - 1) / total),
+        }
+    }
+
+    fn to_verse(verse: u32) -> Verse {
+        u8::try_from(verse)
+            .ok()
+            .and_then(|verse| Verse::try_from(verse).ok())
+            .expect("A verse of a chapter is a valid verse")
+    }
+
+    /// The positions in the canon of the books starting a section
+    fn section_starts(&self) -> Vec<usize> {
+        let books: &[BibleEnum] = self.canon.books();
+
+        (0..books.len())
+            .filter(|position| {
+                *position == 0 || books[*position].section() != books[*position - 1].section()
+            })
+            .collect()
+    }
+
+    /// The index, in the section starts, of the section of the current book
+    fn current_section(&self, section_starts: &[usize]) -> Result<usize, ReadingError> {
+        let position: usize = self
+            .canon
+            .position(&self.current_book)
+            .ok_or(ReadingError::BookNotInCanon)?;
+
+        Ok(section_starts
+            .iter()
+            .rposition(|start| *start <= position)
+            .unwrap_or_default())
+    }
+
+    /// A book of a Bible, borrowed rather than cloned
+    fn book<'a>(bible: &'a Bible, book: &BibleEnum) -> Result<&'a Book, ReadingError> {
+        Ok(bible.try_get(*book)?)
+    }
+
+    /// A chapter of a book of a Bible, borrowed rather than cloned
+    fn chapter<'a>(
+        bible: &'a Bible,
+        book: &BibleEnum,
+        chapter: &ChapterNumber,
+    ) -> Result<&'a Chapter, ReadingError> {
+        Ok(bible.try_get_chapter(*book, *chapter)?)
+    }
+
+    /// The first chapter of a book, which is not always 1
+    fn first_chapter(bible: &Bible, book: &BibleEnum) -> Result<ChapterNumber, ReadingError> {
+        Self::book(bible, book)?
+            .chapters
+            .first()
+            .map(|chapter| *chapter.get_chapter_number())
+            .ok_or(ReadingError::ChapterNotInBook)
+    }
+
     fn validate_fields(
+        bible: &Bible,
+        canon: &Canon,
         book: &BibleEnum,
         chapter: &ChapterNumber,
         verse: &Verse,
     ) -> Result<(), ReadingError> {
-        let current_book: Book = BIBLE[book].clone();
-
-        if let Some(chapter_in_book) = current_book.chapters.get(*chapter) {
-            Self::validate_verse(chapter_in_book, verse)
-        } else {
-            Err(ReadingError::ChapterNotInBook)
+        if !canon.contains(book) {
+            return Err(ReadingError::BookNotInCanon);
         }
+
+        Self::validate_verse(Self::chapter(bible, book, chapter)?, verse)
     }
 
     fn validate_verse(chapter: &Chapter, verse: &Verse) -> Result<(), ReadingError> {
@@ -239,10 +819,83 @@ impl Reading {
 
 #[cfg(test)]
 mod tests {
+    use std::collections::BTreeMap;
+
+    use bible::{
+        citation::CitationStyle,
+        data_pack::{DataPack, PackMetadata},
+    };
     use book::book_components::{chapter_number::ChapterNumber, name::BookName};
 
     use super::*;
 
+    /// A Bible having only a short book of Jude and Genesis in French
+    fn fixture_bible() -> Bible {
+        Bible::from_pack(DataPack {
+            metadata: PackMetadata {
+                name: "Test Bible".to_string(),
+                language: "fr".to_string(),
+                abbreviation: None,
+                license: None,
+            },
+            versification: BTreeMap::from([
+                (BibleEnum::Genesis, vec![31]),
+                (BibleEnum::Jude, vec![2]),
+            ]),
+            text: BTreeMap::new(),
+        })
+        .unwrap()
+    }
+
+    #[test]
+    fn test_in_another_bible() {
+        let bible = fixture_bible();
+
+        let mut reading = Reading::new_in(
+            BibleEnum::Jude,
+            1u8.try_into().unwrap(),
+            2u8.try_into().unwrap(),
+            &bible,
+        )
+        .unwrap();
+
+        // Valid in the default Bible, the same reading is not in the fixture
+        let jude_end = Reading::new(
+            BibleEnum::Jude,
+            1u8.try_into().unwrap(),
+            25u8.try_into().unwrap(),
+        )
+        .unwrap();
+        assert!(jude_end.validate_in(&BIBLE).is_ok());
+        assert_eq!(
+            jude_end.validate_in(&bible).unwrap_err(),
+            ReadingError::VerseNotInChapter
+        );
+
+        // Revelation is not in the fixture
+        assert_eq!(
+            reading.next_verse_in(&1, &bible).unwrap_err(),
+            ReadingError::Bible(BibleError::BookNotFound)
+        );
+
+        reading
+            .modify_reading_in(
+                BibleEnum::Genesis,
+                1u8.try_into().unwrap(),
+                31u8.try_into().unwrap(),
+                &bible,
+            )
+            .unwrap();
+        assert_eq!(reading.display_in(&bible).to_string(), "Genèse 1:31");
+        assert_eq!(reading.to_string(), "Genesis 1:31");
+        assert_eq!(
+            reading
+                .set_current_chapter_in(2u8.try_into().unwrap(), &bible)
+                .unwrap_err(),
+            ReadingError::ChapterNotInBook
+        );
+    }
+
     #[test]
     fn test_create_reading_and_modify() {
         let chapter_number = ChapterNumber::try_from(1u8).unwrap();
@@ -263,7 +916,7 @@ mod tests {
         assert_eq!(reading.current_verse(), &verse);
         assert_eq!(reading.current_chapter(), &chapter_number);
         assert_eq!(
-            BIBLE[reading.current_book].name,
+            BIBLE.try_get(reading.current_book).unwrap().name,
             BookName::try_from("Exodus").unwrap()
         );
     }
@@ -301,6 +954,128 @@ mod tests {
         assert_eq!(reading.to_string(), "Genesis 1:1");
     }
 
+    #[test]
+    fn test_localized_display() {
+        let reading = Reading::new(
+            BibleEnum::SongOfSolomon,
+            2u8.try_into().unwrap(),
+            4u8.try_into().unwrap(),
+        )
+        .unwrap();
+
+        assert_eq!(
+            reading.localized(Locale::French).to_string(),
+            "Cantique des Cantiques 2:4"
+        );
+    }
+
+    #[test]
+    fn test_section() {
+        let mut reading = Reading::new(
+            BibleEnum::Ruth,
+            2u8.try_into().unwrap(),
+            4u8.try_into().unwrap(),
+        )
+        .unwrap();
+
+        reading.next_section(&1).unwrap();
+        assert_eq!(reading.current_book(), &BibleEnum::Job);
+
+        reading.next_section(&2).unwrap();
+        assert_eq!(reading.current_book(), &BibleEnum::Hosea);
+
+        reading.previous_section(&1).unwrap();
+        assert_eq!(reading.current_book(), &BibleEnum::Isaiah);
+
+        reading.set_current_book(BibleEnum::Jude).unwrap();
+        reading.next_section(&1).unwrap();
+        assert_eq!(reading.current_book(), &BibleEnum::Revelation);
+        reading.next_section(&1).unwrap();
+        assert_eq!(reading.current_book(), &BibleEnum::Genesis);
+        reading.previous_section(&1).unwrap();
+        assert_eq!(reading.current_book(), &BibleEnum::Revelation);
+    }
+
+    #[test]
+    fn test_cite() {
+        let reading = Reading::new(
+            BibleEnum::SongOfSolomon,
+            2u8.try_into().unwrap(),
+            4u8.try_into().unwrap(),
+        )
+        .unwrap();
+
+        assert_eq!(
+            reading.cite(&CitationFormatter::new(CitationStyle::Osis)),
+            "Song.2.4"
+        );
+        assert_eq!(
+            reading.cite(&CitationFormatter::new(CitationStyle::Sbl)),
+            "Song 2:4"
+        );
+    }
+
+    #[test]
+    fn test_versification() {
+        let reading = Reading::from_versification(
+            Versification::Hebrew,
+            VerseRef::new(
+                BibleEnum::Malachi,
+                3u8.try_into().unwrap(),
+                19u8.try_into().unwrap(),
+            ),
+        )
+        .unwrap();
+
+        assert_eq!(reading.to_string(), "Malachi 4:1");
+
+        assert_eq!(
+            reading
+                .in_versification(Versification::Hebrew)
+                .unwrap()
+                .to_string(),
+            "Malachi 3:19"
+        );
+    }
+
+    #[test]
+    fn test_canon() {
+        let error = Reading::new(
+            BibleEnum::Tobit,
+            1u8.try_into().unwrap(),
+            1u8.try_into().unwrap(),
+        )
+        .unwrap_err();
+
+        assert_eq!(error, ReadingError::BookNotInCanon);
+
+        let mut reading = Reading::with_canon(
+            Canon::Catholic,
+            BibleEnum::Nehemiah,
+            13u8.try_into().unwrap(),
+            31u8.try_into().unwrap(),
+        )
+        .unwrap();
+
+        reading.next_verse(&1).unwrap();
+
+        assert_eq!(reading.current_book(), &BibleEnum::Tobit);
+
+        reading.next_book(&3).unwrap();
+
+        // The Additions to Esther start at chapter 10
+        assert_eq!(reading.to_string(), "Additions to Esther 10:1");
+
+        reading.previous_book(&3).unwrap();
+
+        assert_eq!(reading.current_book(), &BibleEnum::Tobit);
+
+        assert_eq!(
+            reading.set_canon(Canon::Protestant).unwrap_err(),
+            ReadingError::BookNotInCanon
+        );
+    }
+
     #[test]
     fn test_next_book() {
         let mut reading = Reading::new(
@@ -310,7 +1085,7 @@ mod tests {
         )
         .unwrap();
 
-        let count: u8 = 1;
+        let count: u32 = 1;
 
         reading.next_book(&count).unwrap();
 
@@ -333,7 +1108,7 @@ mod tests {
         )
         .unwrap();
 
-        let mut count: u8 = 1;
+        let mut count: u32 = 1;
 
         reading.next_chapter(&count).unwrap();
 
@@ -343,17 +1118,32 @@ mod tests {
         );
 
         // pass book
-        count = BIBLE[reading.current_book()]
-            .chapters
-            .into_iter()
-            .last()
-            .unwrap()
-            .get_chapter_number()
-            .into();
+        count = u8::from(
+            BIBLE
+                .try_get(*reading.current_book())
+                .unwrap()
+                .chapters
+                .last()
+                .unwrap()
+                .get_chapter_number(),
+        )
+        .into();
 
         reading.next_chapter(&count).unwrap();
 
         assert_eq!(reading.current_book(), &BibleEnum::Exodus);
+
+        // The last chapter of a book is not skipped
+        reading
+            .modify_reading(
+                BibleEnum::Genesis,
+                49u8.try_into().unwrap(),
+                1u8.try_into().unwrap(),
+            )
+            .unwrap();
+        reading.next_chapter(&1).unwrap();
+
+        assert_eq!(reading.to_string(), "Genesis 50:1");
     }
 
     #[test]
@@ -365,16 +1155,19 @@ mod tests {
         )
         .unwrap();
 
-        let mut count: u8 = 1;
+        let mut count: u32 = 1;
 
         reading.next_verse(&count).unwrap();
 
         assert_eq!(reading.current_verse(), &Verse::try_from(2u8).unwrap());
 
         // pass chapter
-        count = BIBLE[reading.current_book()].chapters[reading.current_chapter()]
-            .get_max_verse()
-            .into();
+        count = u8::from(
+            Reading::chapter(&BIBLE, reading.current_book(), reading.current_chapter())
+                .unwrap()
+                .get_max_verse(),
+        )
+        .into();
 
         reading.next_verse(&count).unwrap();
 
@@ -388,9 +1181,10 @@ mod tests {
 
         reading
             .set_current_chapter(
-                *BIBLE[reading.current_book()]
+                *BIBLE
+                    .try_get(*reading.current_book())
+                    .unwrap()
                     .chapters
-                    .into_iter()
                     .last()
                     .unwrap()
                     .get_chapter_number(),
@@ -399,7 +1193,9 @@ mod tests {
 
         reading
             .set_current_verse(
-                *BIBLE[reading.current_book()].chapters[reading.current_chapter()].get_max_verse(),
+                *Reading::chapter(&BIBLE, reading.current_book(), reading.current_chapter())
+                    .unwrap()
+                    .get_max_verse(),
             )
             .unwrap();
 
@@ -417,7 +1213,7 @@ mod tests {
         )
         .unwrap();
 
-        let count: u8 = 1;
+        let count: u32 = 1;
 
         reading.previous_book(&count).unwrap();
 
@@ -438,7 +1234,7 @@ mod tests {
         )
         .unwrap();
 
-        let count: u8 = 1;
+        let count: u32 = 1;
 
         reading.previous_chapter(&count).unwrap();
 
@@ -462,23 +1258,245 @@ mod tests {
         )
         .unwrap();
 
-        let count: u8 = 1;
+        let count: u32 = 1;
 
         reading.previous_verse(&count).unwrap();
 
         assert_eq!(reading.current_verse(), &Verse::try_from(1u8).unwrap());
 
-        // pass chapter
+        // pass chapter, to its last verse
         reading.previous_verse(&count).unwrap();
 
-        assert_eq!(
-            reading.current_chapter(),
-            &ChapterNumber::try_from(1u8).unwrap()
-        );
+        assert_eq!(reading.to_string(), "Exodus 1:22");
 
         // pass book
+        reading.set_current_verse(1u8.try_into().unwrap()).unwrap();
         reading.previous_verse(&count).unwrap();
 
-        assert_eq!(reading.current_book(), &BibleEnum::Genesis);
+        assert_eq!(reading.to_string(), "Genesis 50:26");
+    }
+
+    #[test]
+    fn test_carry_over() {
+        let mut reading = Reading::new(
+            BibleEnum::Genesis,
+            50u8.try_into().unwrap(),
+            20u8.try_into().unwrap(),
+        )
+        .unwrap();
+
+        // Genesis 50 has 26 verses and Exodus 1 has 22
+        reading.next_verse(&40).unwrap();
+        assert_eq!(reading.to_string(), "Exodus 2:12");
+
+        reading.previous_verse(&40).unwrap();
+        assert_eq!(reading.to_string(), "Genesis 50:20");
+
+        reading.next_verse(&300).unwrap();
+        reading.previous_verse(&300).unwrap();
+        assert_eq!(reading.to_string(), "Genesis 50:20");
+
+        reading.next_chapter(&3).unwrap();
+        assert_eq!(reading.to_string(), "Exodus 3:1");
+
+        reading.previous_chapter(&4).unwrap();
+        assert_eq!(reading.to_string(), "Genesis 49:1");
+
+        // Across the end of the canon
+        reading
+            .modify_reading(
+                BibleEnum::Revelation,
+                22u8.try_into().unwrap(),
+                20u8.try_into().unwrap(),
+            )
+            .unwrap();
+        reading.next_verse(&3).unwrap();
+        assert_eq!(reading.to_string(), "Genesis 1:2");
+
+        reading.previous_verse(&3).unwrap();
+        assert_eq!(reading.to_string(), "Revelation 22:20");
+
+        reading.next_chapter(&2).unwrap();
+        assert_eq!(reading.to_string(), "Genesis 2:1");
+    }
+
+    #[test]
+    fn test_count_zero_and_whole_cycles() {
+        let mut reading = Reading::new(
+            BibleEnum::Exodus,
+            1u8.try_into().unwrap(),
+            5u8.try_into().unwrap(),
+        )
+        .unwrap();
+
+        reading.next_verse(&0).unwrap();
+        reading.previous_verse(&0).unwrap();
+        reading.next_chapter(&0).unwrap();
+        reading.previous_chapter(&0).unwrap();
+        reading.next_book(&0).unwrap();
+        reading.previous_book(&0).unwrap();
+        reading.next_section(&0).unwrap();
+        reading.previous_section(&0).unwrap();
+        assert_eq!(reading.to_string(), "Exodus 1:5");
+
+        // Going through the whole canon comes back to the same verse
+        let canon_verses: u32 = Canon::Protestant
+            .books()
+            .iter()
+            .map(|book| BIBLE[book].chapters.verse_count())
+            .sum();
+        reading.next_verse(&canon_verses).unwrap();
+        assert_eq!(reading.to_string(), "Exodus 1:5");
+
+        reading.previous_verse(&u32::MAX).unwrap();
+        reading.next_verse(&u32::MAX).unwrap();
+        assert_eq!(reading.to_string(), "Exodus 1:5");
+
+        reading
+            .next_book(&(Canon::Protestant.books().len() as u32 + 1))
+            .unwrap();
+        assert_eq!(reading.current_book(), &BibleEnum::Leviticus);
+
+        reading.previous_chapter(&1189).unwrap();
+        assert_eq!(reading.to_string(), "Leviticus 1:1");
+    }
+
+    #[test]
+    fn test_boundary_wrap() {
+        let mut reading = Reading::new(
+            BibleEnum::Revelation,
+            22u8.try_into().unwrap(),
+            21u8.try_into().unwrap(),
+        )
+        .unwrap();
+
+        assert_eq!(reading.boundary_policy(), BoundaryPolicy::Wrap);
+
+        reading.next_verse(&1).unwrap();
+        assert_eq!(reading.to_string(), "Genesis 1:1");
+        assert_eq!(reading.cycles(), 1);
+
+        reading.previous_verse(&1).unwrap();
+        assert_eq!(reading.to_string(), "Revelation 22:21");
+        assert_eq!(reading.cycles(), 0);
+
+        reading.next_book(&1).unwrap();
+        reading.next_chapter(&1189).unwrap();
+        assert_eq!(reading.to_string(), "Genesis 1:1");
+        assert_eq!(reading.cycles(), 2);
+
+        let canon_verses: u32 = Canon::Protestant
+            .books()
+            .iter()
+            .map(|book| BIBLE[book].chapters.verse_count())
+            .sum();
+        reading.next_verse(&(canon_verses * 3)).unwrap();
+        assert_eq!(reading.to_string(), "Genesis 1:1");
+        assert_eq!(reading.cycles(), 5);
+
+        reading.previous_book(&67).unwrap();
+        assert_eq!(reading.current_book(), &BibleEnum::Revelation);
+        assert_eq!(reading.cycles(), 3);
+    }
+
+    #[test]
+    fn test_boundary_clamp() {
+        let mut reading = Reading::new(
+            BibleEnum::Revelation,
+            20u8.try_into().unwrap(),
+            1u8.try_into().unwrap(),
+        )
+        .unwrap();
+        reading.set_boundary_policy(BoundaryPolicy::Clamp);
+
+        reading.next_chapter(&3).unwrap();
+        assert_eq!(reading.to_string(), "Revelation 22:1");
+
+        reading.next_verse(&100).unwrap();
+        assert_eq!(reading.to_string(), "Revelation 22:21");
+
+        reading
+            .modify_reading(
+                BibleEnum::Jude,
+                1u8.try_into().unwrap(),
+                1u8.try_into().unwrap(),
+            )
+            .unwrap();
+        reading.next_book(&5).unwrap();
+        assert_eq!(reading.to_string(), "Revelation 1:1");
+
+        reading.next_section(&5).unwrap();
+        assert_eq!(reading.current_book(), &BibleEnum::Revelation);
+
+        reading
+            .modify_reading(
+                BibleEnum::Exodus,
+                1u8.try_into().unwrap(),
+                3u8.try_into().unwrap(),
+            )
+            .unwrap();
+        reading.previous_book(&3).unwrap();
+        assert_eq!(reading.to_string(), "Genesis 1:1");
+
+        reading.set_current_verse(3u8.try_into().unwrap()).unwrap();
+        reading.previous_verse(&10).unwrap();
+        assert_eq!(reading.to_string(), "Genesis 1:1");
+
+        reading
+            .set_current_chapter(3u8.try_into().unwrap())
+            .unwrap();
+        reading.previous_chapter(&u32::MAX).unwrap();
+        assert_eq!(reading.to_string(), "Genesis 1:1");
+        assert_eq!(reading.cycles(), 0);
+    }
+
+    #[test]
+    fn test_boundary_error() {
+        let mut reading = Reading::new(
+            BibleEnum::Revelation,
+            22u8.try_into().unwrap(),
+            19u8.try_into().unwrap(),
+        )
+        .unwrap();
+        reading.set_boundary_policy(BoundaryPolicy::Error);
+
+        assert_eq!(reading.next_verse(&5), Err(ReadingError::EndOfBible));
+        assert_eq!(reading.next_chapter(&1), Err(ReadingError::EndOfBible));
+        assert_eq!(reading.next_book(&1), Err(ReadingError::EndOfBible));
+        assert_eq!(reading.next_section(&1), Err(ReadingError::EndOfBible));
+        assert_eq!(reading.to_string(), "Revelation 22:19");
+
+        reading.next_verse(&2).unwrap();
+        assert_eq!(reading.to_string(), "Revelation 22:21");
+
+        reading
+            .modify_reading(
+                BibleEnum::Genesis,
+                2u8.try_into().unwrap(),
+                1u8.try_into().unwrap(),
+            )
+            .unwrap();
+        assert_eq!(
+            reading.previous_chapter(&2),
+            Err(ReadingError::StartOfBible)
+        );
+        assert_eq!(reading.previous_verse(&40), Err(ReadingError::StartOfBible));
+        assert_eq!(reading.previous_book(&1), Err(ReadingError::StartOfBible));
+        assert_eq!(reading.to_string(), "Genesis 2:1");
+
+        reading.previous_verse(&31).unwrap();
+        assert_eq!(reading.to_string(), "Genesis 1:1");
+    }
+
+    #[test]
+    fn test_saved_without_policy() {
+        let reading: Reading = ron::from_str(
+            "(current_book: exodus, current_chapter: (2), current_verse: (3), canon: protestant)",
+        )
+        .unwrap();
+
+        assert_eq!(reading.boundary_policy(), BoundaryPolicy::Wrap);
+        assert_eq!(reading.cycles(), 0);
+        assert_eq!(reading.to_string(), "Exodus 2:3");
     }
 }
@@ -0,0 +1,91 @@
+//! Walk the whole Bible with the navigation of `Reading`, checking that no step
+//! allocates, as bulk operations like simulating a reading plan do thousands
+//! of steps.
+
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    cell::Cell,
+};
+
+use bible::{bible_enum::BibleEnum, BIBLE};
+use read::reading::Reading;
+
+struct CountingAllocator;
+
+thread_local! {
+    static ALLOCATIONS: Cell<usize> = const { Cell::new(0) };
+}
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let _ = ALLOCATIONS.try_with(|allocations| allocations.set(allocations.get() + 1));
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+}
+
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+/// The number of allocations of the current thread while running a function
+fn count_allocations(run: impl FnOnce()) -> usize {
+    let before = ALLOCATIONS.with(Cell::get);
+    run();
+    ALLOCATIONS.with(Cell::get) - before
+}
+
+fn genesis() -> Reading {
+    Reading::new(
+        BibleEnum::Genesis,
+        1u8.try_into().unwrap(),
+        1u8.try_into().unwrap(),
+    )
+    .unwrap()
+}
+
+#[test]
+fn test_navigation_does_not_allocate() {
+    // Build the Bible before counting
+    let chapter_count = BIBLE.chapter_count();
+
+    let mut reading = genesis();
+
+    let allocations = count_allocations(|| {
+        // The protestant canon wraps around after Revelation 22:21
+        for _ in 0..31102 {
+            reading.next_verse(&1).unwrap();
+        }
+    });
+
+    assert_eq!(allocations, 0);
+    assert_eq!(reading.to_verse_ref(), genesis().to_verse_ref());
+    assert_eq!(reading.cycles(), 1);
+
+    let allocations = count_allocations(|| {
+        for _ in 0..1189 {
+            reading.next_chapter(&1).unwrap();
+        }
+        assert_eq!(reading.to_verse_ref(), genesis().to_verse_ref());
+        assert_eq!(reading.cycles(), 2);
+
+        for _ in 0..1189 {
+            reading.previous_chapter(&1).unwrap();
+        }
+
+        for _ in 0..1000 {
+            reading.previous_verse(&1).unwrap();
+            reading.set_current_verse(1u8.try_into().unwrap()).unwrap();
+        }
+
+        for _ in 0..66 {
+            reading.next_book(&1).unwrap();
+            reading.previous_book(&2).unwrap();
+        }
+    });
+
+    assert!(chapter_count >= 1189);
+    assert_eq!(allocations, 0);
+}